@@ -0,0 +1,92 @@
+//! Counts the heap allocations made loading and parsing every registered day.
+//!
+//! `cargo bench --bench allocations` prints, per day, what reading the input
+//! into an [`Input`] and parsing it allocates, next to what copying each line
+//! into its own `String` would have allocated on top of reading the file.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use advent2023::input::Input;
+use advent2023::registry::DAYS;
+
+/// The system allocator, counting every allocation and the bytes asked for.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(new_size.saturating_sub(layout.size()), Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static COUNTING: Counting = Counting;
+
+/// Allocations and bytes allocated while running `f`.
+fn count<T>(f: impl FnOnce() -> T) -> (T, (usize, usize)) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let result = f();
+    let counted = (
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        BYTES.load(Ordering::Relaxed) - bytes,
+    );
+    (result, counted)
+}
+
+fn main() {
+    println!(
+        "{:>3}  {:>16}  {:>16}  {:>16}",
+        "day", "load", "parse", "String per line"
+    );
+    let mut totals = [(0, 0); 3];
+    for day in DAYS {
+        let (input, load) = count(|| Input::from_data_file(day.input).unwrap());
+        let (lines, split) = count(|| input.lines());
+        let load = (load.0 + split.0, load.1 + split.1);
+        let (parsed, parse) = count(|| day.parse(&lines).unwrap());
+        drop(parsed);
+        let (copies, copied) = count(|| {
+            lines
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<String>>()
+        });
+        drop(copies);
+        let counts = [load, parse, copied];
+        let cells: Vec<String> = counts
+            .iter()
+            .map(|(allocations, bytes)| format!("{} / {} B", allocations, bytes))
+            .collect();
+        println!(
+            "{:>3}  {:>16}  {:>16}  {:>16}",
+            day.number, cells[0], cells[1], cells[2]
+        );
+        for (total, (allocations, bytes)) in totals.iter_mut().zip(counts) {
+            total.0 += allocations;
+            total.1 += bytes;
+        }
+    }
+    let cells: Vec<String> = totals
+        .iter()
+        .map(|(allocations, bytes)| format!("{} / {} B", allocations, bytes))
+        .collect();
+    println!(
+        "{:>3}  {:>16}  {:>16}  {:>16}",
+        "all", cells[0], cells[1], cells[2]
+    );
+}
@@ -0,0 +1,117 @@
+//! Benchmarks parse, part one and part two of every registered day.
+//!
+//! `cargo bench` runs against the real inputs in `data/`. Set
+//! `AOC_BENCH_EXAMPLES=1` to run against the example inputs instead, as a
+//! quick smoke test that every day still parses and solves.
+use std::env;
+use std::hint::black_box;
+use std::time::Duration;
+
+use advent2023::input::Input;
+use advent2023::registry::{Day, DAYS};
+use advent2023::solution::{Answer, Part};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// A part that cannot be benchmarked yet because it takes too long to finish.
+struct Skip {
+    day: u8,
+    part: Part,
+    /// Whether the part is also too slow on the example input.
+    on_example: bool,
+    reason: &'static str,
+}
+
+const SKIPPED: &[Skip] = &[Skip {
+    day: 12,
+    part: Part::Two,
+    on_example: true,
+    reason: "brute-forces every unfolded arrangement",
+}];
+
+fn skip_reason(day: &Day, part: Part, examples: bool) -> Option<&'static str> {
+    SKIPPED
+        .iter()
+        .find(|skip| skip.day == day.number && skip.part == part && (!examples || skip.on_example))
+        .map(|skip| skip.reason)
+}
+
+fn use_examples() -> bool {
+    env::var("AOC_BENCH_EXAMPLES").is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+fn input_path(day: &Day, part: Part, examples: bool) -> Option<&'static str> {
+    if examples {
+        day.example(part)
+    } else {
+        Some(day.input)
+    }
+}
+
+fn bench_day(c: &mut Criterion, day: &Day, examples: bool) {
+    let name = format!("day{:02}", day.number);
+    let mut group = c.benchmark_group(&name);
+    for part in [Part::One, Part::Two] {
+        let Some(path) = input_path(day, part, examples) else {
+            eprintln!("skipping {} part {}: no example input", name, part);
+            continue;
+        };
+        let input = Input::from_data_file(path).unwrap_or_else(|error| panic!("{}", error));
+        let lines = input.lines();
+        // both parts usually share an input, so only time parsing it once
+        let parse_id = match part {
+            Part::One => Some("parse"),
+            Part::Two if input_path(day, Part::One, examples) != Some(path) => {
+                Some("parse_part_two")
+            }
+            Part::Two => None,
+        };
+        if let Some(parse_id) = parse_id {
+            group.bench_function(parse_id, |b| {
+                b.iter(|| day.parse(black_box(&lines)).unwrap())
+            });
+        }
+        if let Some(reason) = skip_reason(day, part, examples) {
+            eprintln!("skipping {} part {}: {}", name, part, reason);
+            continue;
+        }
+        let parsed = day
+            .parse(&lines)
+            .unwrap_or_else(|error| panic!("{}", error.in_file(path)));
+        if parsed.solve(part) == Answer::Unsolved {
+            eprintln!("skipping {} part {}: unsolved", name, part);
+            continue;
+        }
+        let id = match part {
+            Part::One => "part_one",
+            Part::Two => "part_two",
+        };
+        group.bench_function(id, |b| b.iter(|| parsed.solve(black_box(part))));
+    }
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    let examples = use_examples();
+    for day in DAYS {
+        bench_day(c, day, examples);
+    }
+}
+
+fn config() -> Criterion {
+    // several days take a second or more per run, so keep the sample count low
+    let criterion = Criterion::default().sample_size(10);
+    if use_examples() {
+        criterion
+            .warm_up_time(Duration::from_millis(100))
+            .measurement_time(Duration::from_millis(500))
+    } else {
+        criterion
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = config();
+    targets = bench_days
+}
+criterion_main!(benches);
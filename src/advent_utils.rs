@@ -1,11 +1,586 @@
-use std::fs;
+//! Parsing numbers, fields and sections out of lines, and the shared day arguments.
 
-pub fn get_lines_from_filepath(filepath: &str) -> Vec<String> {
-    let contents: String =
-        fs::read_to_string(filepath).expect("Something went wrong reading the file");
-    let mut lines: Vec<String> = Vec::new();
-    for line in contents.lines() {
-        lines.push(line.to_string());
-    }
+use std::fmt;
+use std::str::FromStr;
+
+use log::LevelFilter;
+
+use crate::error::AocError;
+use crate::generate::DEFAULT_SIZE;
+use crate::solution::Part;
+
+/// Parse every line with `parse`, tagging any error with its 1-based line number.
+pub fn parse_lines<'a, T>(
+    lines: &[&'a str],
+    parse: impl Fn(&'a str) -> Result<T, AocError>,
+) -> Result<Vec<T>, AocError> {
     lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|error| error.at_line(index + 1, line)))
+        .collect()
+}
+
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, AocError> {
+    text.trim()
+        .parse::<T>()
+        .map_err(|_| AocError::parse(format!("expected a number, found `{}`", text.trim())))
+}
+
+/// Parse `field`, a slice of `line`, as a number, pointing any error at the field's column.
+pub fn parse_number_at<T: FromStr>(line: &str, field: &str) -> Result<T, AocError> {
+    parse_number(field).map_err(|error| error.at_column(column_of(line, field)))
+}
+
+/// The 1-based character column at which `fragment`, a slice of `line`, starts.
+/// Fragments that do not come from `line` point at the first column.
+pub fn column_of(line: &str, fragment: &str) -> usize {
+    let offset = (fragment.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    if offset.saturating_add(fragment.len()) <= line.len() && line.is_char_boundary(offset) {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+/// The column just past the end of `line`, for errors about something missing.
+pub fn end_column(line: &str) -> usize {
+    line.chars().count() + 1
+}
+
+/// Something odd about an input that normalising it could not fix, like a
+/// character the puzzles never use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputWarning {
+    /// 1-based line and character column, if the warning is about one place.
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for InputWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}:{}: {}", line, column, self.message),
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Tidy up input that has been through a browser or another operating system:
+/// strip a byte order mark, turn `\r\n` and lone `\r` line endings into `\n`,
+/// and drop whitespace at the end of each line and blank lines at the end of
+/// the input. The result ends in a single newline unless it is empty.
+///
+/// Also returns warnings for anything left that puzzles do not expect: an
+/// empty input, blank lines at the start, and tabs, other control characters
+/// or non-ASCII characters, reported once per line.
+pub fn normalize_input(text: &str) -> (String, Vec<InputWarning>) {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let unified = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut lines: Vec<&str> = unified.split('\n').map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    let mut warnings = Vec::new();
+    if lines.is_empty() {
+        warnings.push(InputWarning {
+            line: None,
+            column: None,
+            message: "the input is empty".to_string(),
+        });
+    } else if lines[0].is_empty() {
+        warnings.push(InputWarning {
+            line: Some(1),
+            column: None,
+            message: "the input starts with a blank line".to_string(),
+        });
+    }
+    for (index, line) in lines.iter().enumerate() {
+        let odd = line
+            .chars()
+            .enumerate()
+            .find(|(_, c)| c.is_control() || !c.is_ascii());
+        if let Some((column, c)) = odd {
+            let kind = if c.is_control() {
+                "control character"
+            } else {
+                "non-ASCII character"
+            };
+            warnings.push(InputWarning {
+                line: Some(index + 1),
+                column: Some(column + 1),
+                message: format!("unexpected {} {:?} (U+{:04X})", kind, c, c as u32),
+            });
+        }
+    }
+
+    let mut normalized = lines.join("\n");
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    (normalized, warnings)
+}
+
+/// Split `lines` into the runs of non-blank lines between blank ones. Each
+/// section comes with the number of lines before it, ready for
+/// [`AocError::after_line`].
+pub fn sections<S: AsRef<str>>(lines: &[S]) -> Vec<(usize, &[S])> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    for (index, line) in lines.iter().enumerate() {
+        if line.as_ref().trim().is_empty() {
+            if index > start {
+                sections.push((start, &lines[start..index]));
+            }
+            start = index + 1;
+        }
+    }
+    if lines.len() > start {
+        sections.push((start, &lines[start..]));
+    }
+    sections
+}
+
+/// The first section, as split by [`sections`], whose first line starts with `header`.
+pub fn find_section<'a, S: AsRef<str>>(lines: &'a [S], header: &str) -> Option<(usize, &'a [S])> {
+    sections(lines)
+        .into_iter()
+        .find(|(_, section)| section[0].as_ref().starts_with(header))
+}
+
+/// Every run of digits in `line`, with the `-` or `+` right before it, as
+/// slices of `line`. Everything else in between is skipped.
+pub fn integer_fields(line: &str) -> Vec<&str> {
+    let bytes = line.as_bytes();
+    let mut fields = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+        let mut start = index;
+        if start > 0 && matches!(bytes[start - 1], b'-' | b'+') {
+            start -= 1;
+        }
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        fields.push(&line[start..index]);
+    }
+    fields
+}
+
+/// Every signed integer in `line`, as found by [`integer_fields`], pointing
+/// any that do not fit in `T` at their column.
+pub fn signed_integers<T: FromStr>(line: &str) -> Result<Vec<T>, AocError> {
+    integer_fields(line)
+        .into_iter()
+        .map(|field| parse_number_at(line, field))
+        .collect()
+}
+
+/// Split `text` wherever any of `delimiters` appears, dropping empty pieces.
+/// Where two delimiters match at the same place the longer one is used.
+pub fn split_any<'a>(text: &'a str, delimiters: &[&str]) -> Vec<&'a str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+    while index < text.len() {
+        let matched = delimiters
+            .iter()
+            .filter(|delimiter| !delimiter.is_empty() && text[index..].starts_with(*delimiter))
+            .map(|delimiter| delimiter.len())
+            .max();
+        match matched {
+            Some(length) => {
+                if index > start {
+                    pieces.push(&text[start..index]);
+                }
+                index += length;
+                start = index;
+            }
+            None => index += text[index..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    if text.len() > start {
+        pieces.push(&text[start..]);
+    }
+    pieces
+}
+
+pub const DAY_ARGS_USAGE: &str =
+    "<day> [--part 1|2] [--example | --input <path> | -] [--render <image>] [--format text|json]";
+
+/// Where a day should read its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's real input under `data/`.
+    Default,
+    /// The day's example input from the puzzle text.
+    Example,
+    File(String),
+    Stdin,
+}
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// A line like `Day 7 part 2: 5905` per part.
+    Text,
+    /// A [`PartReport`](crate::report::PartReport) per part, one JSON object a line.
+    Json,
+}
+
+/// Options shared by every day: which day, which parts and which input, and
+/// where to save a picture of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayArgs {
+    pub day: u8,
+    pub parts: Vec<Part>,
+    pub input: InputSource,
+    /// A `.png` or `.ppm` file to draw the input into.
+    pub render: Option<String>,
+    pub format: OutputFormat,
+}
+
+/// The day number that starts `args`.
+fn parse_day(args: &[String]) -> Result<u8, AocError> {
+    match args.first().map(|day| day.parse::<u8>()) {
+        Some(Ok(day)) => Ok(day),
+        Some(Err(_)) => Err(AocError::Usage(format!("invalid day: {}", args[0]))),
+        None => Err(AocError::Usage("expected a day number".to_string())),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, AocError> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(AocError::Usage(format!("invalid part: {}", value))),
+    }
+}
+
+fn set_input(input: &mut InputSource, source: InputSource) -> Result<(), AocError> {
+    if *input != InputSource::Default {
+        return Err(AocError::Usage(
+            "only one of --example, --input and - may be given".to_string(),
+        ));
+    }
+    *input = source;
+    Ok(())
+}
+
+/// Parse `<day> [--part 1|2] [--example | --input <path> | -] [--render <image>] [--format text|json]`.
+pub fn parse_day_args(args: &[String]) -> Result<DayArgs, AocError> {
+    let day = parse_day(args)?;
+    let mut parts: Vec<Part> = vec![Part::One, Part::Two];
+    let mut input: InputSource = InputSource::Default;
+    let mut render: Option<String> = None;
+    let mut format: OutputFormat = OutputFormat::Text;
+    let mut remaining = args[1..].iter();
+    while let Some(arg) = remaining.next() {
+        match arg.as_str() {
+            "--part" => match remaining.next() {
+                Some(value) => parts = vec![parse_part(value)?],
+                None => return Err(AocError::Usage("--part needs a value".to_string())),
+            },
+            "--example" => set_input(&mut input, InputSource::Example)?,
+            "--input" => match remaining.next().map(|path| path.as_str()) {
+                Some("-") => set_input(&mut input, InputSource::Stdin)?,
+                Some(path) => set_input(&mut input, InputSource::File(path.to_string()))?,
+                None => return Err(AocError::Usage("--input needs a path".to_string())),
+            },
+            "-" => set_input(&mut input, InputSource::Stdin)?,
+            "--render" => match remaining.next() {
+                Some(path) => render = Some(path.to_string()),
+                None => return Err(AocError::Usage("--render needs a path".to_string())),
+            },
+            "--format" => match remaining.next().map(|format| format.as_str()) {
+                Some("text") => format = OutputFormat::Text,
+                Some("json") => format = OutputFormat::Json,
+                Some(other) => return Err(AocError::Usage(format!("invalid format: {}", other))),
+                None => return Err(AocError::Usage("--format needs a value".to_string())),
+            },
+            _ => return Err(AocError::Usage(format!("unexpected argument: {}", arg))),
+        }
+    }
+    Ok(DayArgs {
+        day,
+        parts,
+        input,
+        render,
+        format,
+    })
+}
+
+pub const GEN_ARGS_USAGE: &str = "<day> [--size <n>] [--seed <n>]";
+
+/// Options for generating a random input for a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenArgs {
+    pub day: u8,
+    pub size: usize,
+    /// The seed to generate from, or `None` to pick one at random.
+    pub seed: Option<u64>,
+}
+
+fn parse_option<T: FromStr>(name: &str, value: Option<&String>) -> Result<T, AocError> {
+    let value = value.ok_or_else(|| AocError::Usage(format!("{} needs a value", name)))?;
+    value
+        .parse::<T>()
+        .map_err(|_| AocError::Usage(format!("invalid {}: {}", name, value)))
+}
+
+/// Parse `<day> [--size <n>] [--seed <n>]`.
+pub fn parse_gen_args(args: &[String]) -> Result<GenArgs, AocError> {
+    let day = parse_day(args)?;
+    let mut size: usize = DEFAULT_SIZE;
+    let mut seed: Option<u64> = None;
+    let mut remaining = args[1..].iter();
+    while let Some(arg) = remaining.next() {
+        match arg.as_str() {
+            "--size" => size = parse_option("--size", remaining.next())?,
+            "--seed" => seed = Some(parse_option("--seed", remaining.next())?),
+            _ => return Err(AocError::Usage(format!("unexpected argument: {}", arg))),
+        }
+    }
+    Ok(GenArgs { day, size, seed })
+}
+
+pub const VERBOSITY_USAGE: &str = "[-q | -v | -vv]";
+
+/// Take the `-q`, `-v` and `-vv` flags out of `args`, wherever they are, and
+/// return how much to log with the arguments that are left.
+///
+/// Only warnings and errors are logged by default. `-q` leaves just errors,
+/// `-v` adds the solutions' debug output and `-vv` (or `-v -v`) their traces.
+pub fn take_verbosity(args: &[String]) -> Result<(LevelFilter, Vec<String>), AocError> {
+    let mut quiet = false;
+    let mut verbose = 0;
+    let mut remaining: Vec<String> = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "-q" | "--quiet" => quiet = true,
+            "-v" | "--verbose" => verbose += 1,
+            "-vv" => verbose += 2,
+            _ => remaining.push(arg.clone()),
+        }
+    }
+    let level = match (quiet, verbose) {
+        (true, 0) => LevelFilter::Error,
+        (true, _) => {
+            return Err(AocError::Usage(
+                "only one of -q and -v may be given".to_string(),
+            ))
+        }
+        (false, 0) => LevelFilter::Warn,
+        (false, 1) => LevelFilter::Debug,
+        (false, _) => LevelFilter::Trace,
+    };
+    Ok((level, remaining))
+}
+
+#[cfg(test)]
+fn to_args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_parse_day_args_defaults() {
+    let day_args = parse_day_args(&to_args(&["7"])).unwrap();
+    assert_eq!(day_args.day, 7);
+    assert_eq!(day_args.parts, vec![Part::One, Part::Two]);
+    assert_eq!(day_args.input, InputSource::Default);
+    assert_eq!(day_args.render, None);
+    assert_eq!(day_args.format, OutputFormat::Text);
+}
+
+#[test]
+fn test_parse_day_args_input_sources() {
+    let day_args = parse_day_args(&to_args(&["7", "--part", "2", "--example"])).unwrap();
+    assert_eq!(day_args.parts, vec![Part::Two]);
+    assert_eq!(day_args.input, InputSource::Example);
+    let day_args = parse_day_args(&to_args(&["7", "--input", "other.txt"])).unwrap();
+    assert_eq!(day_args.input, InputSource::File("other.txt".to_string()));
+    let day_args = parse_day_args(&to_args(&["7", "--input", "-"])).unwrap();
+    assert_eq!(day_args.input, InputSource::Stdin);
+    let day_args = parse_day_args(&to_args(&["7", "-"])).unwrap();
+    assert_eq!(day_args.input, InputSource::Stdin);
+    let day_args = parse_day_args(&to_args(&["10", "--render", "loop.png", "--example"])).unwrap();
+    assert_eq!(day_args.input, InputSource::Example);
+    assert_eq!(day_args.render, Some("loop.png".to_string()));
+    let day_args = parse_day_args(&to_args(&["7", "--format", "json"])).unwrap();
+    assert_eq!(day_args.format, OutputFormat::Json);
+}
+
+#[test]
+fn test_parse_day_args_errors() {
+    assert!(parse_day_args(&to_args(&[])).is_err());
+    assert!(parse_day_args(&to_args(&["seven"])).is_err());
+    assert!(parse_day_args(&to_args(&["7", "--part", "3"])).is_err());
+    assert!(parse_day_args(&to_args(&["7", "--example", "-"])).is_err());
+    assert!(parse_day_args(&to_args(&["7", "--input"])).is_err());
+    assert!(parse_day_args(&to_args(&["7", "--render"])).is_err());
+    assert!(parse_day_args(&to_args(&["7", "--format", "xml"])).is_err());
+}
+
+#[test]
+fn test_parse_gen_args() {
+    let gen_args = parse_gen_args(&to_args(&["7"])).unwrap();
+    assert_eq!(
+        gen_args,
+        GenArgs {
+            day: 7,
+            size: DEFAULT_SIZE,
+            seed: None
+        }
+    );
+    let gen_args = parse_gen_args(&to_args(&["12", "--seed", "42", "--size", "3"])).unwrap();
+    assert_eq!((gen_args.size, gen_args.seed), (3, Some(42)));
+    assert!(parse_gen_args(&to_args(&["12", "--size"])).is_err());
+    assert!(parse_gen_args(&to_args(&["12", "--size", "big"])).is_err());
+    assert!(parse_gen_args(&to_args(&["12", "--seed", "-1"])).is_err());
+    assert!(parse_gen_args(&to_args(&["12", "--example"])).is_err());
+}
+
+#[test]
+fn test_take_verbosity() {
+    let (level, args) = take_verbosity(&to_args(&["run", "4", "-"])).unwrap();
+    assert_eq!(level, LevelFilter::Warn);
+    assert_eq!(args, to_args(&["run", "4", "-"]));
+    let (level, args) = take_verbosity(&to_args(&["-v", "run", "4"])).unwrap();
+    assert_eq!(level, LevelFilter::Debug);
+    assert_eq!(args, to_args(&["run", "4"]));
+    let (level, _) = take_verbosity(&to_args(&["run", "4", "-vv"])).unwrap();
+    assert_eq!(level, LevelFilter::Trace);
+    let (level, _) = take_verbosity(&to_args(&["-v", "run", "-v", "4"])).unwrap();
+    assert_eq!(level, LevelFilter::Trace);
+    let (level, _) = take_verbosity(&to_args(&["run", "4", "-q"])).unwrap();
+    assert_eq!(level, LevelFilter::Error);
+    assert!(take_verbosity(&to_args(&["-q", "run", "4", "-v"])).is_err());
+}
+
+#[test]
+fn test_parse_lines_reports_line_number() {
+    let lines = ["1", "2", "three"];
+    let error = parse_lines(&lines, parse_number::<i32>).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 3: expected a number, found `three`"
+    );
+    assert_eq!(
+        parse_lines(&lines[..2], parse_number::<i32>).unwrap(),
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn test_column_of() {
+    let line = "Card 1: 41 48 | 83 86";
+    let (_, numbers) = line.split_once(':').unwrap();
+    assert_eq!(column_of(line, numbers), 8);
+    assert_eq!(
+        column_of(line, numbers.split_whitespace().nth(1).unwrap()),
+        12
+    );
+    assert_eq!(column_of(line, "elsewhere"), 1);
+    assert_eq!(end_column(line), 22);
+    assert_eq!(parse_number_at::<i32>(line, &line[5..6]).unwrap(), 1);
+    assert_eq!(parse_number_at::<i32>(line, &line[16..18]).unwrap(), 83);
+    let error = parse_number_at::<i32>(line, &line[14..15]).unwrap_err();
+    assert_eq!(
+        error.at_line(4, line).to_string(),
+        "line 4:15: expected a number, found `|`"
+    );
+}
+
+#[test]
+fn test_sections() {
+    let lines = to_args(&[
+        "seeds: 1 2",
+        "",
+        "",
+        "a map:",
+        "1 2 3",
+        "   ",
+        "b map:",
+        "4 5 6",
+        "",
+    ]);
+    let found = sections(&lines);
+    assert_eq!(found.len(), 3);
+    assert_eq!(found[0], (0, &lines[0..1]));
+    assert_eq!(found[1], (3, &lines[3..5]));
+    assert_eq!(found[2], (6, &lines[6..8]));
+    assert_eq!(find_section(&lines, "b map:"), Some((6, &lines[6..8])));
+    assert_eq!(find_section(&lines, "c map:"), None);
+    let borrowed = ["#.", "", ".#"];
+    assert_eq!(
+        sections(&borrowed),
+        vec![(0, &borrowed[..1]), (2, &borrowed[2..])]
+    );
+    assert!(sections::<&str>(&[]).is_empty());
+}
+
+#[test]
+fn test_signed_integers() {
+    let line = "Sensor at x=-2, y=15: closest beacon is at x=+3, y=10-4";
+    assert_eq!(integer_fields(line), vec!["-2", "15", "+3", "10", "-4"]);
+    assert_eq!(
+        signed_integers::<i64>(line).unwrap(),
+        vec![-2, 15, 3, 10, -4]
+    );
+    assert!(integer_fields("no numbers - here").is_empty());
+    let error = signed_integers::<i8>("ok 12, too big 300").unwrap_err();
+    assert_eq!(error.to_string(), "expected a number, found `300`");
+    assert_eq!(
+        error.at_line(1, "").to_string(),
+        "line 1:16: expected a number, found `300`"
+    );
+}
+
+#[test]
+fn test_split_any() {
+    assert_eq!(
+        split_any("Card 1: 41 48 | 83 86", &[": ", " | ", " "]),
+        vec!["Card", "1", "41", "48", "83", "86"]
+    );
+    assert_eq!(
+        split_any("px{a<2006:qkq,m>2090:A,rfg}", &["{", "}", ","]),
+        vec!["px", "a<2006:qkq", "m>2090:A", "rfg"]
+    );
+    assert_eq!(split_any("é,ü", &[","]), vec!["é", "ü"]);
+    assert_eq!(split_any("abc", &[]), vec!["abc"]);
+    assert!(split_any(",,", &[","]).is_empty());
+}
+
+#[test]
+fn test_normalize_input() {
+    let (text, warnings) = normalize_input("\u{feff}rn=1,cm-\r\nqp=3 \t\r\n\r\n\r\n");
+    assert_eq!(text, "rn=1,cm-\nqp=3\n");
+    assert!(warnings.is_empty());
+    let (text, _) = normalize_input("#.#\r.#.\r\r#..");
+    assert_eq!(text, "#.#\n.#.\n\n#..\n");
+    let (text, warnings) = normalize_input("  \n\n");
+    assert_eq!(text, "");
+    assert_eq!(warnings[0].to_string(), "the input is empty");
+}
+
+#[test]
+fn test_normalize_input_warnings() {
+    let (text, warnings) = normalize_input("\nab\tc\nok\nx\u{a0}y\u{2019}\n");
+    assert_eq!(text, "\nab\tc\nok\nx\u{a0}y\u{2019}\n");
+    let warnings: Vec<String> = warnings.iter().map(|warning| warning.to_string()).collect();
+    assert_eq!(
+        warnings,
+        vec![
+            "line 1: the input starts with a blank line",
+            "line 2:3: unexpected control character '\\t' (U+0009)",
+            "line 4:2: unexpected non-ASCII character '\\u{a0}' (U+00A0)",
+        ]
+    );
 }
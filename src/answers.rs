@@ -0,0 +1,151 @@
+//! The `answers.toml` manifest of known-correct answers.
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::advent_utils::column_of;
+use crate::data;
+use crate::error::AocError;
+use crate::solution::Part;
+
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+/// Which of a day's inputs an answer belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    /// The day's real input under `data/`.
+    Input,
+    /// The day's example input from the puzzle text.
+    Example,
+}
+
+impl InputKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputKind::Input => "input",
+            InputKind::Example => "example",
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PartAnswers {
+    part_one: Option<i64>,
+    part_two: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    input: Option<PartAnswers>,
+    example: Option<PartAnswers>,
+}
+
+/// Point a TOML error at the line and column its span starts at.
+fn toml_error(contents: &str, error: &toml::de::Error) -> AocError {
+    let parse_error = AocError::parse(error.message().trim_end());
+    let Some(span) = error.span() else {
+        return parse_error;
+    };
+    let line_start = contents[..span.start]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    let line_text = contents[line_start..].lines().next().unwrap_or("");
+    let line_number = contents[..line_start].matches('\n').count() + 1;
+    parse_error
+        .at_column(column_of(line_text, &contents[span.start..span.start]))
+        .at_line(line_number, line_text)
+}
+
+/// The known-correct answers from `answers.toml`, keyed by day, input and part.
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+impl Answers {
+    pub fn from_toml(contents: &str) -> Result<Answers, AocError> {
+        let tables: BTreeMap<String, DayAnswers> =
+            toml::from_str(contents).map_err(|error| toml_error(contents, &error))?;
+        let mut days = BTreeMap::new();
+        for (name, day_answers) in tables {
+            let day: u8 = name
+                .strip_prefix("day")
+                .and_then(|number| number.parse::<u8>().ok())
+                .ok_or_else(|| {
+                    AocError::parse(format!(
+                        "expected a table like `[day04]`, found `[{}]`",
+                        name
+                    ))
+                })?;
+            days.insert(day, day_answers);
+        }
+        Ok(Answers { days })
+    }
+
+    /// Load the manifest, looking for it with [`data::resolve`].
+    pub fn load(filepath: &str) -> Result<Answers, AocError> {
+        let found = data::resolve(filepath)?;
+        let contents: String = fs::read_to_string(&found).map_err(|source| AocError::Io {
+            path: found.display().to_string(),
+            source,
+        })?;
+        Answers::from_toml(&contents).map_err(|error| error.in_file(filepath))
+    }
+
+    /// The days that have at least one recorded answer.
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+
+    pub fn expected(&self, day: u8, input: InputKind, part: Part) -> Option<i128> {
+        let day_answers = self.days.get(&day)?;
+        let part_answers = match input {
+            InputKind::Input => day_answers.input.as_ref()?,
+            InputKind::Example => day_answers.example.as_ref()?,
+        };
+        let answer = match part {
+            Part::One => part_answers.part_one,
+            Part::Two => part_answers.part_two,
+        };
+        answer.map(i128::from)
+    }
+}
+
+#[test]
+fn test_answers_from_toml() {
+    let answers = Answers::from_toml(
+        "[day04]\ninput = { part_one = 18653 }\nexample = { part_one = 13, part_two = 30 }\n",
+    )
+    .unwrap();
+    assert_eq!(
+        answers.expected(4, InputKind::Input, Part::One),
+        Some(18653)
+    );
+    assert_eq!(answers.expected(4, InputKind::Input, Part::Two), None);
+    assert_eq!(answers.expected(4, InputKind::Example, Part::Two), Some(30));
+    assert_eq!(answers.expected(5, InputKind::Example, Part::One), None);
+    assert_eq!(answers.days().collect::<Vec<u8>>(), vec![4]);
+}
+
+#[test]
+fn test_answers_from_malformed_toml() {
+    let error = Answers::from_toml("[four]\ninput = { part_one = 1 }\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected a table like `[day04]`, found `[four]`"
+    );
+    let error = Answers::from_toml("[day04]\ninput = { part_three = 1 }\n").unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("line 2:11: unknown field `part_three`"));
+}
+
+#[test]
+fn test_answers_manifest_loads() {
+    let answers = Answers::load(ANSWERS_PATH).unwrap();
+    assert_eq!(answers.expected(4, InputKind::Example, Part::One), Some(13));
+}
@@ -0,0 +1,39 @@
+//! Writes the solutions' log records to stderr, away from the answers.
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        match record.level() {
+            Level::Error => eprintln!("error: {}", record.args()),
+            Level::Warn => eprintln!("warning: {}", record.args()),
+            Level::Info => eprintln!("{}", record.args()),
+            // traces say which day they came from, as `day04` rather than `advent2023::day04`
+            Level::Debug | Level::Trace => {
+                let target = record.target();
+                let module = target.rsplit("::").next().unwrap_or(target);
+                let level = record.level().as_str().to_lowercase();
+                eprintln!("{} {}: {}", level, module, record.args());
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Send log records at `level` and above to stderr.
+pub fn init(level: LevelFilter) {
+    log::set_logger(&LOGGER).expect("the logger is only set once");
+    log::set_max_level(level);
+}
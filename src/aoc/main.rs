@@ -0,0 +1,307 @@
+mod logger;
+mod play;
+
+use std::env;
+use std::io::{self, IsTerminal};
+use std::process;
+use std::time::Instant;
+
+use log::{warn, LevelFilter};
+
+use advent2023::advent_utils::{
+    parse_day_args, parse_gen_args, take_verbosity, InputSource, OutputFormat, DAY_ARGS_USAGE,
+    GEN_ARGS_USAGE, VERBOSITY_USAGE,
+};
+use advent2023::answers::{Answers, InputKind, ANSWERS_PATH};
+use advent2023::error::AocError;
+use advent2023::generate::rng_from_seed;
+use advent2023::input::Input;
+use advent2023::registry::{get_day, Day, DAYS};
+use advent2023::report::{sha256_hex, PartReport};
+use advent2023::solution::{Answer, Part};
+
+const VERIFY_USAGE: &str = "[<day>...]";
+const STEP_USAGE: &str = "<day> [--part 1|2] [--example | --input <path> | -]";
+
+/// How many pixels wide and high each cell of a rendered grid is.
+const RENDER_SCALE: usize = 4;
+
+fn get_input_path(day: &Day, part: Part, input: &InputSource) -> Result<String, AocError> {
+    match input {
+        InputSource::Default => Ok(day.input.to_string()),
+        InputSource::Example => match day.example(part) {
+            Some(example) => Ok(example.to_string()),
+            None => Err(AocError::Usage(format!(
+                "day {} has no example input",
+                day.number
+            ))),
+        },
+        InputSource::File(path) => Ok(path.to_string()),
+        InputSource::Stdin => Ok("<stdin>".to_string()),
+    }
+}
+
+/// An input read for `run`, kept while the parts that use it are solved.
+struct Loaded {
+    path: String,
+    input: Input,
+    input_sha256: String,
+}
+
+fn run(args: &[String]) -> Result<(), AocError> {
+    let day_args = parse_day_args(args)?;
+    let day = get_day(day_args.day)
+        .ok_or_else(|| AocError::Usage(format!("day {} is not solved", day_args.day)))?;
+    // both parts usually read the same input, so only load and check it once
+    let mut loaded: Option<Loaded> = None;
+    for part in day_args.parts {
+        let path: String = get_input_path(day, part, &day_args.input)?;
+        if loaded.as_ref().map(|loaded| &loaded.path) != Some(&path) {
+            let input = load_input(&day_args.input, &path)?;
+            loaded = Some(Loaded {
+                input_sha256: sha256_hex(input.text()),
+                path,
+                input,
+            });
+        }
+        let loaded = loaded.as_ref().expect("the input was just loaded");
+        let lines = loaded.input.lines();
+        let parse_start = Instant::now();
+        let parsed = day
+            .parse(&lines)
+            .map_err(|error| error.in_file(&loaded.path))?;
+        let parse_time = parse_start.elapsed();
+        let solve_start = Instant::now();
+        let answer = parsed.solve(part);
+        let solve_time = solve_start.elapsed();
+        match day_args.format {
+            OutputFormat::Text => println!("Day {} part {}: {}", day.number, part, answer),
+            OutputFormat::Json => {
+                let report = PartReport::new(
+                    day.number,
+                    part,
+                    &loaded.path,
+                    &loaded.input_sha256,
+                    &answer,
+                    parse_time,
+                    solve_time,
+                );
+                println!("{}", report.to_json());
+            }
+        }
+    }
+    if let (Some(image_path), Some(loaded)) = (&day_args.render, &loaded) {
+        let image = day
+            .render(&loaded.input.lines())
+            .map_err(|error| error.in_file(&loaded.path))?
+            .ok_or_else(|| AocError::Usage(format!("day {} cannot render", day.number)))?;
+        image.scaled(RENDER_SCALE).save(image_path)?;
+        // keep stdout to the reports when they are being read by another program
+        match day_args.format {
+            OutputFormat::Text => println!("Rendered {} to {}", loaded.path, image_path),
+            OutputFormat::Json => eprintln!("Rendered {} to {}", loaded.path, image_path),
+        }
+    }
+    Ok(())
+}
+
+/// Read the input at `path`, which came from `source`, and warn about it.
+fn load_input(source: &InputSource, path: &str) -> Result<Input, AocError> {
+    let input = match source {
+        InputSource::Stdin => Input::from_stdin()?,
+        InputSource::File(_) => Input::from_file(path)?,
+        InputSource::Default | InputSource::Example => Input::from_data_file(path)?,
+    };
+    print_warnings(path, &input);
+    Ok(input)
+}
+
+/// Replay the simulation behind a day in the terminal, one step at a time.
+fn step(args: &[String]) -> Result<(), AocError> {
+    let day_args = parse_day_args(args)?;
+    if day_args.render.is_some() || day_args.format != OutputFormat::Text {
+        return Err(AocError::Usage(
+            "--render and --format only apply to run".to_string(),
+        ));
+    }
+    let day = get_day(day_args.day)
+        .ok_or_else(|| AocError::Usage(format!("day {} is not solved", day_args.day)))?;
+    let path = get_input_path(day, day_args.parts[0], &day_args.input)?;
+    let input = load_input(&day_args.input, &path)?;
+    let lines = input.lines();
+    let parsed = day.parse(&lines).map_err(|error| error.in_file(&path))?;
+    let mut stepper = parsed.stepper().ok_or_else(|| {
+        AocError::Usage(format!(
+            "day {} has no simulation to step through",
+            day.number
+        ))
+    })?;
+    if !io::stdout().is_terminal() {
+        return Err(AocError::Usage(
+            "aoc step needs a terminal to draw in".to_string(),
+        ));
+    }
+    let title = format!("day {}: {}", day.number, path);
+    // anything logged while the frames are drawn would scribble over them
+    log::set_max_level(LevelFilter::Off);
+    play::play(stepper.as_mut(), &title).map_err(|source| AocError::Io {
+        path: "<terminal>".to_string(),
+        source,
+    })
+}
+
+/// Print a random input for a day, reporting the seed if it was not given so
+/// that the same input can be made again.
+fn generate(args: &[String]) -> Result<(), AocError> {
+    let gen_args = parse_gen_args(args)?;
+    let day = get_day(gen_args.day)
+        .ok_or_else(|| AocError::Usage(format!("day {} is not solved", gen_args.day)))?;
+    let seed = match gen_args.seed {
+        Some(seed) => seed,
+        None => {
+            let seed = rand::random::<u64>();
+            eprintln!("seed: {}", seed);
+            seed
+        }
+    };
+    print!("{}", day.generate(&mut rng_from_seed(seed), gen_args.size));
+    Ok(())
+}
+
+/// Tell the user about anything odd in `input` that might trip up a solution.
+fn print_warnings(path: &str, input: &Input) {
+    for warning in input.warnings() {
+        warn!("{}: {}", path, warning);
+    }
+}
+
+fn solve_file(day: &Day, path: &str, part: Part) -> Result<Answer, AocError> {
+    let input = Input::from_data_file(path)?;
+    print_warnings(path, &input);
+    day.solve(&input.lines(), part)
+        .map_err(|error| error.in_file(path))
+}
+
+/// Check every registered day, or just the given ones, against `answers.toml`.
+/// Returns whether every recorded answer matched.
+fn verify(args: &[String]) -> Result<bool, AocError> {
+    let answers = Answers::load(ANSWERS_PATH)?;
+    if let Some(day) = answers.days().find(|day| get_day(*day).is_none()) {
+        return Err(
+            AocError::parse(format!("day {} has answers but is not registered", day))
+                .in_file(ANSWERS_PATH),
+        );
+    }
+    let mut days: Vec<&Day> = Vec::new();
+    for arg in args {
+        let day = arg
+            .parse::<u8>()
+            .ok()
+            .and_then(get_day)
+            .ok_or_else(|| AocError::Usage(format!("invalid day: {}", arg)))?;
+        days.push(day);
+    }
+    if days.is_empty() {
+        days = DAYS.iter().collect();
+    }
+
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut missing = 0;
+    let mut errors: Vec<AocError> = Vec::new();
+    println!(
+        "{:>3}  {:>4}  {:<7}  {:>16}  {:>16}  result",
+        "day", "part", "input", "expected", "answer"
+    );
+    for day in days {
+        for input in [InputKind::Input, InputKind::Example] {
+            for part in [Part::One, Part::Two] {
+                let path = match input {
+                    InputKind::Input => day.input,
+                    InputKind::Example => match day.example(part) {
+                        Some(example) => example,
+                        None => continue,
+                    },
+                };
+                let expected = answers.expected(day.number, input, part);
+                // parts without a recorded answer are not run, some never finish
+                let (answer, result) = match expected {
+                    None => {
+                        missing += 1;
+                        ("-".to_string(), "missing")
+                    }
+                    Some(expected) => match solve_file(day, path, part) {
+                        Ok(Answer::Number(answer)) if answer == expected => {
+                            passed += 1;
+                            (answer.to_string(), "pass")
+                        }
+                        Ok(answer) => {
+                            failed += 1;
+                            (answer.to_string(), "fail")
+                        }
+                        Err(error) => {
+                            failed += 1;
+                            errors.push(error);
+                            ("error".to_string(), "fail")
+                        }
+                    },
+                };
+                let expected = expected.map_or("-".to_string(), |expected| expected.to_string());
+                println!(
+                    "{:>3}  {:>4}  {:<7}  {:>16}  {:>16}  {}",
+                    day.number,
+                    part,
+                    input.name(),
+                    expected,
+                    answer,
+                    result
+                );
+            }
+        }
+    }
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+    for error in errors {
+        eprintln!("error: {}", error.diagnostic());
+    }
+    Ok(failed == 0)
+}
+
+/// Run the command `args` names, once logging is set up.
+fn dispatch(args: &[String]) -> Result<(), AocError> {
+    let (level, args) = take_verbosity(args)?;
+    logger::init(level);
+    match args.first().map(|command| command.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("step") => step(&args[1..]),
+        Some("gen") => generate(&args[1..]),
+        Some("verify") => verify(&args[1..]).map(|passed| {
+            if !passed {
+                process::exit(1);
+            }
+        }),
+        _ => Err(AocError::Usage("expected a command".to_string())),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = dispatch(&args);
+    match result {
+        Ok(()) => {}
+        Err(AocError::Usage(message)) => {
+            eprintln!("{}", message);
+            eprintln!("usage: aoc {} run {}", VERBOSITY_USAGE, DAY_ARGS_USAGE);
+            eprintln!("       aoc {} verify {}", VERBOSITY_USAGE, VERIFY_USAGE);
+            eprintln!("       aoc {} step {}", VERBOSITY_USAGE, STEP_USAGE);
+            eprintln!("       aoc {} gen {}", VERBOSITY_USAGE, GEN_ARGS_USAGE);
+            process::exit(2);
+        }
+        Err(error) => {
+            eprintln!("error: {}", error.diagnostic());
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Drawing a [`Playback`] in the terminal and stepping it from the keyboard.
+
+use std::io::{self, Stdout, Write};
+use std::time::Duration;
+
+use advent2023::stepper::Playback;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute, queue};
+
+const KEYS: &str = "space play/pause  \u{2190}/\u{2192} step  home restart  +/- speed  q quit";
+
+const FASTEST: Duration = Duration::from_millis(10);
+const SLOWEST: Duration = Duration::from_millis(5120);
+
+/// Show `playback` full screen until the user quits, restoring the terminal
+/// afterwards however the loop ends.
+pub fn play(playback: &mut dyn Playback, title: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    let result = execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)
+        .and_then(|()| run(playback, title, &mut stdout));
+    let restored = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen);
+    terminal::disable_raw_mode()?;
+    result.and(restored)
+}
+
+fn run(playback: &mut dyn Playback, title: &str, stdout: &mut Stdout) -> io::Result<()> {
+    let mut playing = false;
+    let mut delay = Duration::from_millis(160);
+    loop {
+        draw(playback, title, playing, delay, stdout)?;
+        let timeout = if playing { delay } else { SLOWEST };
+        if !event::poll(timeout)? {
+            if playing && !playback.forward() {
+                playing = false;
+            }
+            continue;
+        }
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        else {
+            continue;
+        };
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Char(' ') => playing = !playing,
+            KeyCode::Right | KeyCode::Char('l') => {
+                playing = false;
+                playback.forward();
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                playing = false;
+                playback.back();
+            }
+            KeyCode::Home => while playback.back() {},
+            KeyCode::Char('+') | KeyCode::Char('=') => delay = (delay / 2).max(FASTEST),
+            KeyCode::Char('-') => delay = (delay * 2).min(SLOWEST),
+            _ => {}
+        }
+    }
+}
+
+fn draw(
+    playback: &dyn Playback,
+    title: &str,
+    playing: bool,
+    delay: Duration,
+    stdout: &mut Stdout,
+) -> io::Result<()> {
+    queue!(
+        stdout,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(ClearType::All)
+    )?;
+    let state = if playing { "playing" } else { "paused" };
+    let finished = if playback.finished() {
+        "  (the simulation has finished)"
+    } else {
+        ""
+    };
+    // raw mode does not return to the start of the line on a newline
+    write!(
+        stdout,
+        "{}  step {}  {} every {} ms{}\r\n{}\r\n\r\n",
+        title,
+        playback.position(),
+        state,
+        delay.as_millis(),
+        finished,
+        KEYS
+    )?;
+    for line in playback.frame().lines() {
+        write!(stdout, "{}\r\n", line)?;
+    }
+    stdout.flush()
+}
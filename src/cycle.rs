@@ -0,0 +1,193 @@
+//! Cycle detection for simulations that step one state to the next.
+//!
+//! A deterministic step function over finitely many states always falls into
+//! a loop. Once the loop is known, the state after any number of steps is the
+//! state after a handful of them, so puzzles that ask about step one billion
+//! only have to simulate until the first repeat.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The shape of an iterated sequence: `tail` states that never come back,
+/// then a loop of `length` states that repeats forever.
+///
+/// Iteration 0 is the initial state, so the first repeated state is the one
+/// at iteration `tail`, and it comes back at iteration `tail + length`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub tail: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest iteration whose state is the same as iteration `n`'s.
+    pub fn earliest_equivalent(&self, n: usize) -> usize {
+        if n < self.tail {
+            n
+        } else {
+            self.tail + (n - self.tail) % self.length
+        }
+    }
+
+    /// The state at iteration `n`, stepping from `initial` no further than the
+    /// end of the first loop.
+    pub fn state_at<T>(&self, initial: T, mut step: impl FnMut(&T) -> T, n: usize) -> T {
+        (0..self.earliest_equivalent(n)).fold(initial, |state, _| step(&state))
+    }
+}
+
+/// Find the cycle of the sequence `initial`, `step(initial)`, ... with Brent's
+/// algorithm, which keeps only two states in memory at a time.
+///
+/// Loops forever if the sequence never repeats.
+pub fn find_cycle<T: Clone + PartialEq>(initial: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // find the length by letting the hare run ahead of a tortoise that
+    // teleports to it every power of two steps
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    // with the hare a whole loop ahead, the two meet where the loop starts
+    let mut tortoise = initial.clone();
+    let mut hare = (0..length).fold(initial, |state, _| step(&state));
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+    Cycle { tail, length }
+}
+
+/// Find the cycle like [`find_cycle`], with Floyd's tortoise and hare instead
+/// of Brent's algorithm. It steps more often, but each step is simpler.
+pub fn find_cycle_floyd<T: Clone + PartialEq>(initial: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let halfway = step(&hare);
+        hare = step(&halfway);
+    }
+    let mut tortoise = initial;
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { tail, length }
+}
+
+/// Every distinct state of a sequence, in order, and the cycle they fall into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleHistory<T> {
+    pub cycle: Cycle,
+    states: Vec<T>,
+}
+
+impl<T> CycleHistory<T> {
+    /// The state at iteration `n`, without stepping again.
+    pub fn state_at(&self, n: usize) -> &T {
+        &self.states[self.cycle.earliest_equivalent(n)]
+    }
+
+    /// The states from iteration 0 up to the end of the first loop.
+    pub fn states(&self) -> &[T] {
+        &self.states
+    }
+}
+
+/// Find the cycle by remembering every state seen so far. Each state is only
+/// stepped once, which suits expensive steps, and the history then answers
+/// [`CycleHistory::state_at`] directly.
+///
+/// Loops forever, and keeps on allocating, if the sequence never repeats.
+pub fn find_cycle_hashed<T: Clone + Eq + Hash>(
+    initial: T,
+    mut step: impl FnMut(&T) -> T,
+) -> CycleHistory<T> {
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+    loop {
+        if let Some(&tail) = seen.get(&state) {
+            let length = states.len() - tail;
+            return CycleHistory {
+                cycle: Cycle { tail, length },
+                states,
+            };
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+fn rho(value: &u64) -> u64 {
+    // 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ...
+    if *value == 7 {
+        3
+    } else {
+        value + 1
+    }
+}
+
+#[test]
+fn test_find_cycle() {
+    let expected = Cycle { tail: 3, length: 5 };
+    assert_eq!(find_cycle(0, rho), expected);
+    assert_eq!(find_cycle_floyd(0, rho), expected);
+    assert_eq!(find_cycle_hashed(0, rho).cycle, expected);
+    // a pure loop has no tail, and a fixed point is a loop of one
+    assert_eq!(find_cycle(3, rho), Cycle { tail: 0, length: 5 });
+    assert_eq!(find_cycle_floyd(3, rho), Cycle { tail: 0, length: 5 });
+    assert_eq!(find_cycle(9, |_| 9), Cycle { tail: 0, length: 1 });
+    assert_eq!(find_cycle_floyd(9, |_| 9), Cycle { tail: 0, length: 1 });
+    assert_eq!(find_cycle(0, |_| 9), Cycle { tail: 1, length: 1 });
+    assert_eq!(find_cycle_floyd(0, |_| 9), Cycle { tail: 1, length: 1 });
+}
+
+#[test]
+fn test_find_cycle_agrees_on_longer_sequences() {
+    // x^2 + 1 mod 1_000_003 from a few seeds
+    let step = |x: &u64| (x * x + 1) % 1_000_003;
+    for seed in [0, 2, 17, 999_999] {
+        let brent = find_cycle(seed, step);
+        assert_eq!(find_cycle_floyd(seed, step), brent);
+        assert_eq!(find_cycle_hashed(seed, step).cycle, brent);
+    }
+}
+
+#[test]
+fn test_state_at() {
+    let cycle = find_cycle(0, rho);
+    assert_eq!(cycle.earliest_equivalent(2), 2);
+    assert_eq!(cycle.earliest_equivalent(8), 3);
+    assert_eq!(cycle.earliest_equivalent(1_000_000_000), 5);
+    assert_eq!(cycle.state_at(0, rho, 1_000_000_000), 5);
+    let history = find_cycle_hashed(0, rho);
+    assert_eq!(history.states(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+    for n in 0..30 {
+        let stepped = (0..n).fold(0, |state, _| rho(&state));
+        assert_eq!(*history.state_at(n), stepped);
+        assert_eq!(cycle.state_at(0, rho, n), stepped);
+    }
+}
@@ -0,0 +1,107 @@
+//! Finding the puzzle inputs and `answers.toml` wherever the program runs.
+//!
+//! Registered inputs are named relative to the repository root, as in
+//! `data/day_14_input.txt`, which only opens from the root itself. Instead
+//! each name is looked for in turn:
+//!
+//! 1. under the directory named by `AOC_DATA_DIR`, which stands in for
+//!    `data/` itself, so `data/day_14_input.txt` is `$AOC_DATA_DIR/day_14_input.txt`;
+//! 2. under the crate root, from `CARGO_MANIFEST_DIR` as cargo sets it when
+//!    running the binary, tests or benchmarks, or else as it was at build time;
+//! 3. under the current directory.
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use crate::error::AocError;
+
+/// The environment variable naming the directory that holds the inputs.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// The prefix registered inputs are named with, which `AOC_DATA_DIR` replaces.
+const DATA_PREFIX: &str = "data/";
+
+/// Every place `path` could be, in the order they are tried, given the data
+/// and crate root directories.
+fn candidates_in(path: &str, data_dir: Option<&Path>, manifest_dir: Option<&Path>) -> Vec<PathBuf> {
+    if Path::new(path).is_absolute() {
+        return vec![PathBuf::from(path)];
+    }
+    let mut candidates = Vec::new();
+    if let (Some(data_dir), Some(name)) = (data_dir, path.strip_prefix(DATA_PREFIX)) {
+        candidates.push(data_dir.join(name));
+    }
+    if let Some(manifest_dir) = manifest_dir {
+        candidates.push(manifest_dir.join(path));
+    }
+    candidates.push(PathBuf::from(path));
+    candidates
+}
+
+/// Every place `path` could be, in the order [`resolve`] tries them.
+pub fn candidates(path: &str) -> Vec<PathBuf> {
+    let data_dir = env::var_os(DATA_DIR_VAR).filter(|dir| !dir.is_empty());
+    let manifest_dir =
+        env::var_os("CARGO_MANIFEST_DIR").unwrap_or_else(|| env!("CARGO_MANIFEST_DIR").into());
+    candidates_in(
+        path,
+        data_dir.as_deref().map(Path::new),
+        Some(Path::new(&manifest_dir)),
+    )
+}
+
+/// The first of [`candidates`] that exists, or an error listing all of them.
+pub fn resolve(path: &str) -> Result<PathBuf, AocError> {
+    let tried = candidates(path);
+    match tried.iter().find(|candidate| candidate.is_file()) {
+        Some(found) => Ok(found.clone()),
+        None => Err(AocError::NotFound {
+            path: path.to_string(),
+            tried,
+        }),
+    }
+}
+
+#[test]
+fn test_candidates_in() {
+    let data = Path::new("/inputs");
+    let root = Path::new("/src/advent");
+    assert_eq!(
+        candidates_in("data/day_14_input.txt", Some(data), Some(root)),
+        vec![
+            PathBuf::from("/inputs/day_14_input.txt"),
+            PathBuf::from("/src/advent/data/day_14_input.txt"),
+            PathBuf::from("data/day_14_input.txt"),
+        ]
+    );
+    // only names under data/ are looked for in the data directory
+    assert_eq!(
+        candidates_in("answers.toml", Some(data), Some(root)),
+        vec![
+            PathBuf::from("/src/advent/answers.toml"),
+            PathBuf::from("answers.toml"),
+        ]
+    );
+    assert_eq!(
+        candidates_in("data/day_1.txt", None, None),
+        vec![PathBuf::from("data/day_1.txt")]
+    );
+    assert_eq!(
+        candidates_in("/tmp/day_1.txt", Some(data), Some(root)),
+        vec![PathBuf::from("/tmp/day_1.txt")]
+    );
+}
+
+#[test]
+fn test_resolve() {
+    // the crate root is always tried, so this works from any directory
+    assert!(resolve("data/day_14_input.txt").unwrap().is_file());
+    let error = resolve("data/no_such_file.txt").unwrap_err();
+    let message = error.to_string();
+    assert!(message.starts_with("could not find data/no_such_file.txt, tried:\n"));
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap();
+    let expected = Path::new(&manifest_dir).join("data/no_such_file.txt");
+    assert!(message.contains(&format!("  {}\n", expected.display())));
+    assert!(message.contains("  data/no_such_file.txt\n"));
+    assert!(message.ends_with("set AOC_DATA_DIR to the directory holding the puzzle inputs"));
+}
@@ -0,0 +1,139 @@
+//! Day 1: Trebuchet?!
+//!
+//! Each calibration line hides a two digit value made of its first and last
+//! digit, where digits may also be spelled out as words.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::advent_utils::{end_column, parse_lines, parse_number};
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+const NUMBER_STRINGS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The digit `line` starts with, either as a numeral or spelled out.
+fn digit_at_start(line: &str) -> Option<char> {
+    let first = line.chars().next()?;
+    if first.is_ascii_digit() {
+        return Some(first);
+    }
+    NUMBER_STRINGS
+        .iter()
+        .position(|number_string| line.starts_with(number_string))
+        .and_then(|index| char::from_digit(index as u32 + 1, 10))
+}
+
+/// The first digit in `line`, either as a numeral or spelled out.
+pub fn find_first_number(line: &str) -> Option<char> {
+    line.char_indices()
+        .find_map(|(index, _)| digit_at_start(&line[index..]))
+}
+
+/// The last digit in `line`, either as a numeral or spelled out.
+pub fn find_last_number(line: &str) -> Option<char> {
+    line.char_indices()
+        .rev()
+        .find_map(|(index, _)| digit_at_start(&line[index..]))
+}
+
+/// The two digit number made of the first and last digit in `line`.
+pub fn calibration_value(line: &str) -> Result<i32, AocError> {
+    match (find_first_number(line), find_last_number(line)) {
+        (Some(first_number), Some(last_number)) => {
+            let line_number = format!("{}{}", first_number, last_number);
+            parse_number(&line_number)
+        }
+        _ => Err(
+            AocError::parse("expected a digit, as a numeral or spelled out")
+                .at_column(end_column(line)),
+        ),
+    }
+}
+
+/// The calibration value of every line, counting spelled out digits.
+pub fn get_calibration_values(lines: &[&str]) -> Result<Vec<i32>, AocError> {
+    parse_lines(lines, calibration_value)
+}
+
+/// Sum the calibration values.
+pub fn part_two(calibration_values: &[i32]) -> i32 {
+    calibration_values.iter().sum()
+}
+
+/// `size` calibration lines of letters, digits and spelled out digits, each
+/// holding at least one digit of either kind. Spelled out digits can run into
+/// each other, as in `eightwo`.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size.max(1) {
+        let pieces = rng.gen_range(1..=6);
+        let digit_at = rng.gen_range(0..pieces);
+        for piece in 0..pieces {
+            let kind = if piece == digit_at {
+                rng.gen_range(1..3)
+            } else {
+                rng.gen_range(0..3)
+            };
+            match kind {
+                0 => {
+                    for _ in 0..rng.gen_range(1..=4) {
+                        text.push(rng.gen_range('a'..='z'));
+                    }
+                }
+                1 => text.push(char::from(b'0' + rng.gen_range(1..=9))),
+                _ => text.push_str(NUMBER_STRINGS.choose(rng).expect("there are nine digits")),
+            }
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Solves day 1 through the [`Solution`] trait.
+pub struct DayOne;
+
+impl Solution for DayOne {
+    type Input<'a> = Vec<i32>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_calibration_values(lines)
+    }
+
+    fn part_one(_calibration_values: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part_two(calibration_values: &Self::Input<'_>) -> Answer {
+        part_two(calibration_values).into()
+    }
+}
+
+#[test]
+fn test_calibration_values() {
+    let lines: Vec<&str> = vec![
+        "two1nine",
+        "eightwothree",
+        "abcone2threexyz",
+        "7pqrstsixteen",
+    ];
+    assert_eq!(
+        get_calibration_values(&lines).unwrap(),
+        vec![29, 83, 13, 76]
+    );
+    // spelled out digits can share letters, and other characters are skipped
+    assert_eq!(calibration_value("éoneight").unwrap(), 18);
+}
+
+#[test]
+fn test_calibration_values_from_malformed_lines() {
+    let lines: Vec<&str> = vec!["two1nine", "garbage line"];
+    let error = get_calibration_values(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2:13: expected a digit, as a numeral or spelled out"
+    );
+}
@@ -0,0 +1,206 @@
+//! Day 2: Cube Conundrum
+//!
+//! Each game reveals handfuls of red, green and blue cubes from a bag.
+
+use log::trace;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::advent_utils::{column_of, end_column, parse_lines, parse_number_at};
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+/// The game number from a line like `Game 12: ...`.
+pub fn get_game_number(line: &str) -> Result<i32, AocError> {
+    let (label, _) = line.split_once(':').ok_or_else(|| {
+        AocError::parse("expected `:` after the game number").at_column(end_column(line))
+    })?;
+    match label.strip_prefix("Game").map(str::trim) {
+        Some(number) if !number.is_empty() => parse_number_at(line, number),
+        _ => Err(AocError::parse("expected `Game` and its number before `:`").at_column(1)),
+    }
+}
+
+/// How many cubes of each colour were shown in one handful.
+#[derive(Debug)]
+pub struct ColorSet {
+    pub green: i32,
+    pub red: i32,
+    pub blue: i32,
+}
+
+impl ColorSet {
+    /// The product of the three counts.
+    pub fn power(&self) -> i32 {
+        self.green * self.red * self.blue
+    }
+}
+
+impl PartialOrd for ColorSet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.green == other.green && self.red == other.red && self.blue == other.blue {
+            Some(std::cmp::Ordering::Equal)
+        } else if self.green >= other.green && self.red >= other.red && self.blue >= other.blue {
+            Some(std::cmp::Ordering::Greater)
+        } else if self.green < other.green && self.red < other.red && self.blue < other.blue {
+            Some(std::cmp::Ordering::Less)
+        } else {
+            None
+        }
+    }
+}
+impl PartialEq for ColorSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.green == other.green && self.red == other.red && self.blue == other.blue
+    }
+}
+/// Parse one handful such as `" 1 green, 2 red, 3 blue"`, a slice of `line`.
+pub fn get_color_set_from_set(line: &str, set_str: &str) -> Result<ColorSet, AocError> {
+    let mut green: i32 = 0;
+    let mut red: i32 = 0;
+    let mut blue: i32 = 0;
+    for number_color in set_str.split(",") {
+        let mut words = number_color.split_whitespace();
+        let (Some(number), Some(color)) = (words.next(), words.next()) else {
+            return Err(AocError::parse("expected a number of cubes and a colour")
+                .at_column(column_of(line, number_color)));
+        };
+        let number: i32 = parse_number_at(line, number)?;
+        match color {
+            "green" => green = number,
+            "red" => red = number,
+            "blue" => blue = number,
+            _ => {
+                return Err(AocError::parse(format!(
+                    "expected `red`, `green` or `blue`, found `{}`",
+                    color
+                ))
+                .at_column(column_of(line, color)))
+            }
+        }
+    }
+    Ok(ColorSet { green, red, blue })
+}
+
+/// The per-colour maximum of two handfuls.
+pub fn return_max_color_set(color_set: &ColorSet, other: &ColorSet) -> ColorSet {
+    ColorSet {
+        green: std::cmp::max(color_set.green, other.green),
+        red: std::cmp::max(color_set.red, other.red),
+        blue: std::cmp::max(color_set.blue, other.blue),
+    }
+}
+
+/// A game's number and the handfuls shown in it.
+#[derive(Debug)]
+pub struct Game {
+    pub id: i32,
+    pub sets: Vec<ColorSet>,
+}
+
+impl Game {
+    /// Parse a line like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+    pub fn from_line(line: &str) -> Result<Game, AocError> {
+        let id = get_game_number(line)?;
+        let (_, sets) = line.split_once(':').expect("get_game_number found the `:`");
+        let sets = sets
+            .split(";")
+            .map(|set| get_color_set_from_set(line, set))
+            .collect::<Result<Vec<ColorSet>, AocError>>()?;
+        Ok(Game { id, sets })
+    }
+}
+
+/// Parse every game.
+pub fn get_games(lines: &[&str]) -> Result<Vec<Game>, AocError> {
+    parse_lines(lines, Game::from_line)
+}
+
+/// Sum the power of the smallest set of cubes that makes each game possible.
+pub fn part_two(games: &[Game]) -> i32 {
+    let mut power_sum = 0;
+    for game in games {
+        trace!("game {}", game.id);
+        let mut max_set = ColorSet {
+            green: 0,
+            red: 0,
+            blue: 0,
+        };
+        for color_set in &game.sets {
+            max_set = return_max_color_set(&max_set, color_set);
+        }
+        power_sum += max_set.power();
+    }
+    power_sum
+}
+
+/// `size` games of one to six handfuls, each showing up to 20 cubes of one,
+/// two or all three colours.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut text = String::new();
+    for game in 1..=size.max(1) {
+        let mut handfuls: Vec<String> = Vec::new();
+        for _ in 0..rng.gen_range(1..=6) {
+            let mut colours = ["red", "green", "blue"];
+            colours.shuffle(rng);
+            let shown = rng.gen_range(1..=3);
+            let cubes: Vec<String> = colours[..shown]
+                .iter()
+                .map(|colour| format!("{} {}", rng.gen_range(1..=20), colour))
+                .collect();
+            handfuls.push(cubes.join(", "));
+        }
+        text.push_str(&format!("Game {}: {}\n", game, handfuls.join("; ")));
+    }
+    text
+}
+
+/// Solves day 2 through the [`Solution`] trait.
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    type Input<'a> = Vec<Game>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_games(lines)
+    }
+
+    fn part_one(_games: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part_two(games: &Self::Input<'_>) -> Answer {
+        part_two(games).into()
+    }
+}
+
+#[test]
+fn test_part_two() {
+    let lines: Vec<&str> = vec![
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+        "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+    ];
+    let games = get_games(&lines).unwrap();
+    assert_eq!(games[1].id, 2);
+    assert_eq!(part_two(&games), 48 + 12);
+}
+
+#[test]
+fn test_games_from_malformed_lines() {
+    let error = Game::from_line("garbage line").unwrap_err();
+    assert_eq!(error.to_string(), "expected `:` after the game number");
+    let lines: Vec<&str> = vec!["Game 1: 3 blue", "Game 2: 3 blue, 4 purple"];
+    let error = get_games(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2:19: expected `red`, `green` or `blue`, found `purple`"
+    );
+    let error = get_games(&["Game x: 3 blue"]).unwrap_err();
+    assert_eq!(error.to_string(), "line 1:6: expected a number, found `x`");
+    let error = get_games(&["Game 3: 3 blue; red"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:16: expected a number of cubes and a colour"
+    );
+}
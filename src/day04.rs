@@ -0,0 +1,238 @@
+//! Day 4: Scratchcards
+//!
+//! Each card lists its winning numbers and the numbers you have; matches
+//! score points in part one and win copies of the following cards in part two.
+
+use log::{debug, trace};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::advent_utils::{column_of, end_column, parse_lines, parse_number_at};
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+/// One card, like `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`.
+#[derive(Debug, Clone)]
+pub struct Scratchcard {
+    pub id: i32,
+    pub winning_numbers: Vec<i32>,
+    pub play_numbers: Vec<i32>,
+}
+
+impl Scratchcard {
+    /// Parse a card, reporting where the line stopped looking like one.
+    pub fn from_line(line: &str) -> Result<Self, AocError> {
+        // strip card number from the line
+        let (card_label, just_winning_numbers_and_play_numbers) =
+            line.split_once(':').ok_or_else(|| {
+                AocError::parse("expected `:` after the card number").at_column(end_column(line))
+            })?;
+        let card_number: i32 = match card_label.split_whitespace().last() {
+            Some(card_number) => parse_number_at(line, card_number)?,
+            None => {
+                return Err(AocError::parse("expected a card number before `:`")
+                    .at_column(column_of(line, just_winning_numbers_and_play_numbers) - 1))
+            }
+        };
+        // split the winning numbers from the play numbers
+        let (winning, play) = just_winning_numbers_and_play_numbers
+            .split_once('|')
+            .ok_or_else(|| {
+                AocError::parse("expected `|` separating winning and played numbers")
+                    .at_column(end_column(line))
+            })?;
+        let mut winning_numbers: Vec<i32> = Vec::new();
+        let mut play_numbers: Vec<i32> = Vec::new();
+        for number in winning.split_whitespace() {
+            winning_numbers.push(parse_number_at(line, number)?);
+        }
+        for number in play.split_whitespace() {
+            play_numbers.push(parse_number_at(line, number)?);
+        }
+        Ok(Self {
+            id: card_number,
+            winning_numbers,
+            play_numbers,
+        })
+    }
+}
+
+impl Scratchcard {
+    /// One point for the first match, doubled for every match after it.
+    pub fn score(&self) -> i32 {
+        let matches: i32 = self.matches();
+        if matches > 0 {
+            2i32.pow((matches - 1) as u32)
+        } else {
+            0
+        }
+    }
+}
+
+impl Scratchcard {
+    /// How many of the played numbers are winning numbers.
+    pub fn matches(&self) -> i32 {
+        let mut matches: i32 = 0;
+        for number in self.play_numbers.iter() {
+            if self.winning_numbers.contains(number) {
+                matches += 1;
+            }
+        }
+        matches
+    }
+}
+
+/// Parse every card, checking they are numbered from 1 in order, as part two
+/// finds the cards a card wins by their numbers.
+pub fn load_input(lines: &[&str]) -> Result<Vec<Scratchcard>, AocError> {
+    let scratchcards = parse_lines(lines, Scratchcard::from_line)?;
+    for (index, scratchcard) in scratchcards.iter().enumerate() {
+        let expected = index as i32 + 1;
+        if scratchcard.id != expected {
+            let error = AocError::parse(format!(
+                "expected card {}, found card {}",
+                expected, scratchcard.id
+            ));
+            return Err(error.at_line(index + 1, lines[index]));
+        }
+    }
+    Ok(scratchcards)
+}
+
+/// Sum the score of every card.
+pub fn part_one(scratchcards: &[Scratchcard]) -> i32 {
+    let mut score: i32 = 0;
+    for scratchcard in scratchcards.iter() {
+        trace!("scratchcard: {:?}", scratchcard);
+        trace!("scratchcard score: {}", scratchcard.score());
+        score += scratchcard.score();
+    }
+    debug!("part one score: {}", score);
+    score
+}
+
+fn get_more_cards(
+    starting_id: i32,
+    number_of_cards: i32,
+    scratchcards: &[Scratchcard],
+) -> Vec<Scratchcard> {
+    // there are no copies of cards past the last one to win
+    let start = (starting_id.max(0) as usize).min(scratchcards.len());
+    let end = (start + number_of_cards.max(0) as usize).min(scratchcards.len());
+    let new_scratchcards: Vec<Scratchcard> = scratchcards[start..end].to_vec();
+    new_scratchcards
+}
+
+fn play_card(input_score: i32, scratchcard: Scratchcard, scratchcards: &[Scratchcard]) -> i32 {
+    let mut running_score = input_score + 1;
+    for extra_scratchcard in get_more_cards(scratchcard.id, scratchcard.matches(), scratchcards) {
+        running_score = play_card(running_score, extra_scratchcard, scratchcards)
+    }
+    running_score
+}
+
+/// Count the cards you end up with once every card has won its copies.
+pub fn part_two(scratchcards: &[Scratchcard]) -> i32 {
+    let mut score: i32 = 0;
+    for scratchcard in scratchcards.iter() {
+        let scratchcard_copy = scratchcard.clone();
+        score = play_card(score, scratchcard_copy, scratchcards)
+    }
+    debug!("part two score: {}", score);
+    score
+}
+
+/// `size` cards of 10 winning numbers and 25 played ones, all from 1 to 99.
+/// Half the cards win nothing, and as the puzzle promises, no card wins copies
+/// of cards past the last one.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let cards = size.max(1);
+    let width = cards.to_string().len();
+    let format_numbers = |numbers: &[i32]| {
+        numbers
+            .iter()
+            .map(|number| format!("{:>2}", number))
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+    let mut text = String::new();
+    for card in 1..=cards {
+        let mut numbers: Vec<i32> = (1..=99).collect();
+        numbers.shuffle(rng);
+        let matches = if rng.gen_bool(0.5) {
+            0
+        } else {
+            rng.gen_range(1..=10).min(cards - card)
+        };
+        let mut played: Vec<i32> = numbers[..matches]
+            .iter()
+            .chain(&numbers[10..35 - matches])
+            .copied()
+            .collect();
+        played.shuffle(rng);
+        text.push_str(&format!(
+            "Card {:>width$}: {} | {}\n",
+            card,
+            format_numbers(&numbers[..10]),
+            format_numbers(&played),
+        ));
+    }
+    text
+}
+
+/// Solves day 4 through the [`Solution`] trait.
+pub struct DayFour;
+
+impl Solution for DayFour {
+    type Input<'a> = Vec<Scratchcard>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        load_input(lines)
+    }
+
+    fn part_one(scratchcards: &Self::Input<'_>) -> Answer {
+        part_one(scratchcards).into()
+    }
+
+    fn part_two(scratchcards: &Self::Input<'_>) -> Answer {
+        part_two(scratchcards).into()
+    }
+}
+
+#[test]
+fn test_scratchcard_from_line() {
+    let scratchcard = Scratchcard::from_line("Card 1: 41 48 83 | 83 86  6 17").unwrap();
+    assert_eq!(scratchcard.id, 1);
+    assert_eq!(scratchcard.winning_numbers, vec![41, 48, 83]);
+    assert_eq!(scratchcard.play_numbers, vec![83, 86, 6, 17]);
+    assert_eq!(scratchcard.score(), 1);
+}
+
+#[test]
+fn test_scratchcard_from_malformed_lines() {
+    let error = Scratchcard::from_line("Card 1: 41 48 83 83 86").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected `|` separating winning and played numbers"
+    );
+    let lines: Vec<&str> = vec!["Card 1: 41 | 83", "Card 2: 4x | 83"];
+    let error = load_input(&lines).unwrap_err();
+    assert_eq!(error.to_string(), "line 2:9: expected a number, found `4x`");
+    let lines: Vec<&str> = vec!["Card 1: 41 48 83 83 86"];
+    let error = load_input(&lines).unwrap_err().in_file("cards.txt");
+    assert_eq!(
+        error.diagnostic(),
+        "could not parse cards.txt:1:23\n  |\n1 | Card 1: 41 48 83 83 86\n  |                       ^ expected `|` separating winning and played numbers"
+    );
+    let lines: Vec<&str> = vec!["Card 1: 41 | 41", "Card 1: 83 | 83"];
+    let error = load_input(&lines).unwrap_err();
+    assert_eq!(error.to_string(), "line 2: expected card 2, found card 1");
+}
+
+#[test]
+fn test_part_two_wins_no_cards_past_the_last() {
+    let lines: Vec<&str> = vec!["Card 1: 41 48 | 41 48", "Card 2: 83 | 83"];
+    let scratchcards = load_input(&lines).unwrap();
+    assert_eq!(part_two(&scratchcards), 3);
+}
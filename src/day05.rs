@@ -0,0 +1,393 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//!
+//! The almanac maps seeds through soil, fertilizer, water, light,
+//! temperature and humidity to a location, one range map at a time.
+
+use log::debug;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::advent_utils::{column_of, end_column, find_section, integer_fields, signed_integers};
+use crate::error::AocError;
+#[cfg(test)]
+use crate::input::Input;
+use crate::intervals::{Interval, PiecewiseMap, RangeSet};
+use crate::solution::{Answer, Solution};
+#[cfg(test)]
+use proptest::prelude::*;
+use rayon::iter::IntoParallelRefIterator;
+use rayon::prelude::*;
+#[cfg(test)]
+use std::collections::HashSet;
+
+/// One `x-to-y map:` section: each range of `lengths[i]` values starting at
+/// `source_range_starts[i]` moves to start at `destination_range_starts[i]`.
+/// Values outside every range map to themselves.
+#[derive(Debug, Clone)]
+pub struct AlmanacMap {
+    pub destination_range_starts: Vec<i64>,
+    pub source_range_starts: Vec<i64>,
+    pub lengths: Vec<i64>,
+}
+
+impl AlmanacMap {
+    /// Read the section of `lines` headed by `map_name`, like `"seed-to-soil map:"`.
+    pub fn from_lines(lines: &[&str], map_name: &str) -> Result<Self, AocError> {
+        let mut destination_range_starts: Vec<i64> = Vec::new();
+        let mut source_range_starts: Vec<i64> = Vec::new();
+        let mut lengths: Vec<i64> = Vec::new();
+        let (start, section) = find_section(lines, map_name)
+            .ok_or_else(|| AocError::parse(format!("expected a `{}` section", map_name)))?;
+        for (index, line) in section.iter().enumerate().skip(1) {
+            let at_line = |error: AocError| error.at_line(start + index + 1, line);
+            let numbers: Vec<i64> = signed_integers(line).map_err(at_line)?;
+            if numbers.len() != 3 {
+                // point at the first number too many, or past the end for too few
+                let column = integer_fields(line)
+                    .get(3)
+                    .map_or(end_column(line), |field| column_of(line, field));
+                let error = AocError::parse(format!(
+                    "expected a destination start, source start and length, found {} numbers",
+                    numbers.len()
+                ));
+                return Err(at_line(error.at_column(column)));
+            }
+            // so that mapping a value or a range can never overflow
+            let (destination, source, length) = (numbers[0], numbers[1], numbers[2]);
+            let fields = integer_fields(line);
+            if source.checked_add(length).is_none() || destination.checked_add(length).is_none() {
+                let error = AocError::parse("expected a range that ends within an `i64`");
+                return Err(at_line(error.at_column(column_of(line, fields[2]))));
+            }
+            if destination.checked_sub(source).is_none() {
+                let error = AocError::parse("expected a range moved less far than an `i64` holds");
+                return Err(at_line(error.at_column(column_of(line, fields[0]))));
+            }
+            destination_range_starts.push(numbers[0]);
+            source_range_starts.push(numbers[1]);
+            lengths.push(numbers[2]);
+        }
+        Ok(Self {
+            destination_range_starts,
+            source_range_starts,
+            lengths,
+        })
+    }
+}
+
+impl AlmanacMap {
+    /// Where `source` ends up under this map.
+    pub fn map(&self, source: i64) -> i64 {
+        let mut destination: i64 = source;
+        for index in 0..self.source_range_starts.len() {
+            let source_range: std::ops::Range<i64> = self.source_range_starts[index]
+                ..self.source_range_starts[index] + self.lengths[index];
+            if source_range.contains(&source) {
+                destination =
+                    source - self.source_range_starts[index] + self.destination_range_starts[index];
+                break;
+            }
+        }
+        destination
+    }
+
+    /// The map as a [`PiecewiseMap`], with each range shifting its values from
+    /// its source start to its destination start.
+    pub fn to_piecewise_map(&self) -> PiecewiseMap {
+        let mut piecewise_map = PiecewiseMap::new();
+        for index in 0..self.source_range_starts.len() {
+            piecewise_map.insert(
+                Interval::with_length(self.source_range_starts[index], self.lengths[index]),
+                self.destination_range_starts[index] - self.source_range_starts[index],
+            );
+        }
+        piecewise_map
+    }
+}
+
+/// The seeds and the seven maps they go through, from seed-to-soil to
+/// humidity-to-location.
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub maps: Vec<AlmanacMap>,
+}
+
+/// Parse the seeds and every map.
+pub fn get_almanac(lines: &[&str]) -> Result<Almanac, AocError> {
+    let seeds = get_seeds(lines)?;
+    let maps = MAP_NAMES
+        .iter()
+        .map(|map_name| AlmanacMap::from_lines(lines, map_name))
+        .collect::<Result<Vec<AlmanacMap>, AocError>>()?;
+    Ok(Almanac { seeds, maps })
+}
+
+/// The lowest location any of the listed seeds ends up at, or `None` if no
+/// seeds are listed.
+pub fn part_one(almanac: &Almanac) -> Option<i64> {
+    let locations: Vec<i64> = almanac
+        .seeds
+        .par_iter()
+        .map(|seed| {
+            almanac
+                .maps
+                .iter()
+                .fold(*seed, |value, almanac_map| almanac_map.map(value))
+        })
+        .collect();
+    let min_location: Option<i64> = locations.into_iter().min();
+    debug!("min location: {:?}", min_location);
+    min_location
+}
+
+/// The headers of the maps, in the order a seed goes through them.
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil map:",
+    "soil-to-fertilizer map:",
+    "fertilizer-to-water map:",
+    "water-to-light map:",
+    "light-to-temperature map:",
+    "temperature-to-humidity map:",
+    "humidity-to-location map:",
+];
+
+/// The lowest location of any seed, reading the seed list as start and length
+/// pairs, or `None` if the list does not pair up or every range is empty.
+pub fn part_two(almanac: &Almanac) -> Option<i64> {
+    let mut values: RangeSet = get_seed_ranges(&almanac.seeds)?;
+    for almanac_map in &almanac.maps {
+        values = almanac_map.to_piecewise_map().map_set(&values);
+    }
+    let min_location: Option<i64> = values.min();
+    debug!("min location: {:?}", min_location);
+    min_location
+}
+
+/// The seeds listed on the `seeds:` line. Read as start and length pairs,
+/// every range must end within an `i64`.
+pub fn get_seeds(lines: &[&str]) -> Result<Vec<i64>, AocError> {
+    let Some((start, section)) = find_section(lines, "seeds:") else {
+        return Err(AocError::parse("expected a `seeds:` line"));
+    };
+    let line = section[0];
+    let seeds: Vec<i64> = signed_integers(line).map_err(|error| error.at_line(start + 1, line))?;
+    let fields = integer_fields(line);
+    if let Some(index) = seeds
+        .chunks_exact(2)
+        .position(|pair| pair[0].checked_add(pair[1]).is_none())
+    {
+        let error = AocError::parse("expected a seed range that ends within an `i64`");
+        return Err(error
+            .at_column(column_of(line, fields[2 * index + 1]))
+            .at_line(start + 1, line));
+    }
+    Ok(seeds)
+}
+
+/// The seeds read as start and length pairs, or `None` if there is a start
+/// left over without a length or a range that ends past an `i64`.
+pub fn get_seed_ranges(seeds: &[i64]) -> Option<RangeSet> {
+    if !seeds.len().is_multiple_of(2) {
+        return None;
+    }
+    seeds
+        .chunks(2)
+        .map(|pair| {
+            let end = pair[0].checked_add(pair[1])?;
+            Some(Interval::new(pair[0], end))
+        })
+        .collect()
+}
+
+/// An almanac of `size` seed ranges and seven maps of up to `size` ranges each.
+/// Each map's source ranges cover a run of values from 0 without gaps or
+/// overlaps, and its destination ranges cover the same run in another order,
+/// so every map is a bijection.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut seeds: Vec<String> = Vec::with_capacity(2 * size);
+    for _ in 0..size {
+        seeds.push(rng.gen_range(0..5_000_000_000_i64).to_string());
+        seeds.push(rng.gen_range(1..=100_000_000_i64).to_string());
+    }
+    let mut text = format!("seeds: {}\n", seeds.join(" "));
+    for map_name in MAP_NAMES {
+        let lengths: Vec<i64> = (0..rng.gen_range(1..=size))
+            .map(|_| rng.gen_range(1..=500_000_000))
+            .collect();
+        let mut order: Vec<usize> = (0..lengths.len()).collect();
+        order.shuffle(rng);
+        let mut destination_starts: Vec<i64> = vec![0; lengths.len()];
+        let mut destination_start = 0;
+        for index in order {
+            destination_starts[index] = destination_start;
+            destination_start += lengths[index];
+        }
+        let mut ranges: Vec<String> = Vec::with_capacity(lengths.len());
+        let mut source_start = 0;
+        for (destination_start, length) in destination_starts.iter().zip(&lengths) {
+            ranges.push(format!("{} {} {}", destination_start, source_start, length));
+            source_start += length;
+        }
+        ranges.shuffle(rng);
+        text.push_str(&format!("\n{}\n{}\n", map_name, ranges.join("\n")));
+    }
+    text
+}
+
+/// Solves day 5 through the [`Solution`] trait.
+pub struct DayFive;
+
+impl Solution for DayFive {
+    type Input<'a> = Almanac;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_almanac(lines)
+    }
+
+    fn part_one(almanac: &Self::Input<'_>) -> Answer {
+        part_one(almanac).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part_two(almanac: &Self::Input<'_>) -> Answer {
+        part_two(almanac).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_five_test_input.txt").unwrap();
+    let almanac = get_almanac(&input.lines()).unwrap();
+    assert_eq!(part_two(&almanac), Some(46));
+}
+
+#[test]
+fn test_seed_ranges() {
+    let lines = ["seeds: 79 14 55 13"];
+    assert_eq!(
+        get_seed_ranges(&get_seeds(&lines).unwrap())
+            .unwrap()
+            .intervals(),
+        &[Interval::new(55, 68), Interval::new(79, 93)]
+    );
+}
+
+#[test]
+fn test_almanac_from_malformed_lines() {
+    let lines: Vec<&str> = vec!["seeds: 79 14", "", "seed-to-soil map:", "50 98 2", "52 50"];
+    let error = get_almanac(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 5:6: expected a destination start, source start and length, found 2 numbers"
+    );
+    let error = get_almanac(&["garbage line"]).unwrap_err();
+    assert_eq!(error.to_string(), "expected a `seeds:` line");
+    let lines: Vec<&str> = vec!["seeds: 79 14", "", "seed-to-sole map:", "50 98 2"];
+    let error = get_almanac(&lines).unwrap_err();
+    assert_eq!(error.to_string(), "expected a `seed-to-soil map:` section");
+    let lines: Vec<&str> = vec!["seeds: 79 99999999999999999999"];
+    let error = get_almanac(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:11: expected a number, found `99999999999999999999`"
+    );
+}
+
+#[test]
+fn test_almanac_with_ranges_past_an_i64() {
+    let lines: Vec<&str> = vec![
+        "seeds: 1 10",
+        "",
+        "seed-to-soil map:",
+        "0 9223372036854775800 100",
+    ];
+    let error = get_almanac(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 4:23: expected a range that ends within an `i64`"
+    );
+    let lines: Vec<&str> = vec!["seed-to-soil map:", "9223372036854775800 -100 1"];
+    let error = AlmanacMap::from_lines(&lines, "seed-to-soil map:").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2:1: expected a range moved less far than an `i64` holds"
+    );
+    let error = get_almanac(&["seeds: 9223372036854775807 10"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:28: expected a seed range that ends within an `i64`"
+    );
+    assert_eq!(get_seed_ranges(&[i64::MAX, 10]), None);
+}
+
+#[test]
+fn test_almanac_without_seed_pairs() {
+    let almanac = Almanac {
+        seeds: Vec::new(),
+        maps: Vec::new(),
+    };
+    assert_eq!(DayFive::part_one(&almanac), Answer::Unsolved);
+    let almanac = Almanac {
+        seeds: vec![79, 14, 55],
+        maps: Vec::new(),
+    };
+    assert_eq!(part_one(&almanac), Some(14));
+    assert_eq!(DayFive::part_two(&almanac), Answer::Unsolved);
+}
+
+/// An almanac map like the puzzle's: blocks of values laid out with gaps
+/// between them, each moved to a place in a shuffled layout of the same blocks.
+#[cfg(test)]
+fn almanac_map() -> impl Strategy<Value = AlmanacMap> {
+    prop::collection::vec((1_i64..20, 0_i64..5), 1..6)
+        .prop_flat_map(|blocks| {
+            let order: Vec<usize> = (0..blocks.len()).collect();
+            (
+                Just(blocks),
+                Just(order).prop_shuffle(),
+                -50_i64..50,
+                -50_i64..50,
+            )
+        })
+        .prop_map(|(blocks, order, source_start, destination_start)| {
+            let layout = |order: &[usize], start: i64| {
+                let mut starts = vec![0; blocks.len()];
+                let mut position = start;
+                for &block in order {
+                    let (length, gap) = blocks[block];
+                    starts[block] = position + gap;
+                    position += gap + length;
+                }
+                starts
+            };
+            let in_order: Vec<usize> = (0..blocks.len()).collect();
+            AlmanacMap {
+                destination_range_starts: layout(&order, destination_start),
+                source_range_starts: layout(&in_order, source_start),
+                lengths: blocks.iter().map(|(length, _)| *length).collect(),
+            }
+        })
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn test_almanac_map_is_a_bijection_over_its_ranges(almanac_map in almanac_map()) {
+        let ranges = |starts: &[i64]| -> HashSet<i64> {
+            starts
+                .iter()
+                .zip(&almanac_map.lengths)
+                .flat_map(|(start, length)| *start..start + length)
+                .collect()
+        };
+        let sources = ranges(&almanac_map.source_range_starts);
+        let destinations = ranges(&almanac_map.destination_range_starts);
+        let mapped: HashSet<i64> = sources.iter().map(|source| almanac_map.map(*source)).collect();
+        // every source lands somewhere different, and between them they cover
+        // every destination
+        prop_assert_eq!(mapped.len(), sources.len());
+        prop_assert_eq!(mapped, destinations);
+    }
+}
@@ -0,0 +1,205 @@
+//! Day 6: Wait For It
+//!
+//! Holding a toy boat's button charges its speed; count the ways to hold it
+//! that beat each race's record distance.
+
+use log::debug;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::advent_utils::{column_of, end_column, parse_number, parse_number_at};
+use crate::error::AocError;
+#[cfg(test)]
+use crate::input::Input;
+use crate::solution::{Answer, Solution};
+
+/// A race that lasts `time` milliseconds with a record of `distance` millimetres.
+#[derive(Debug, Clone, Copy)]
+pub struct Race {
+    pub time: i64,
+    pub distance: i64,
+}
+
+impl Race {
+    /// How many button hold times beat the record.
+    pub fn number_winning_strategies(&self) -> i64 {
+        let mut winning_strategies: i64 = 0;
+        for button_push_time in 1..self.time + 1 {
+            let distance = (self.time - button_push_time) * button_push_time;
+            if distance > self.distance {
+                winning_strategies += 1;
+            }
+        }
+        winning_strategies
+    }
+}
+
+/// The races read both ways: one per column of the `Time:` and `Distance:`
+/// lines, and the single long race you get by ignoring the spaces between the
+/// numbers.
+#[derive(Debug, Clone)]
+pub struct RaceSheet {
+    pub races: Vec<Race>,
+    pub long_race: Race,
+}
+
+/// Line `index` of `lines`, which should start with `label`, and the numbers
+/// after the label as slices of the line.
+fn get_race_line<'a>(
+    lines: &[&'a str],
+    index: usize,
+    label: &str,
+) -> Result<(&'a str, Vec<&'a str>), AocError> {
+    let Some(line) = lines.get(index) else {
+        return Err(AocError::parse(format!("expected a `{}` line", label)));
+    };
+    let numbers = line.strip_prefix(label).ok_or_else(|| {
+        AocError::parse(format!("expected the line to start with `{}`", label))
+            .at_column(1)
+            .at_line(index + 1, line)
+    })?;
+    Ok((line, numbers.split_whitespace().collect()))
+}
+
+/// Parse the `Time:` and `Distance:` lines, checking there is a record for
+/// every race.
+pub fn get_race_sheet(lines: &[&str]) -> Result<RaceSheet, AocError> {
+    let (time_line, times) = get_race_line(lines, 0, "Time:")?;
+    let (distance_line, distances) = get_race_line(lines, 1, "Distance:")?;
+    if times.len() != distances.len() {
+        let error = AocError::parse(format!(
+            "expected {} distances, one for each time, found {}",
+            times.len(),
+            distances.len()
+        ));
+        return Err(error.at_line(2, distance_line));
+    }
+    let mut races: Vec<Race> = Vec::new();
+    for (time, distance) in times.iter().zip(&distances) {
+        races.push(Race {
+            time: parse_number_at(time_line, time).map_err(|error| error.at_line(1, time_line))?,
+            distance: parse_number_at(distance_line, distance)
+                .map_err(|error| error.at_line(2, distance_line))?,
+        });
+    }
+    let joined = |line: &str, fields: &[&str], line_number: usize| {
+        parse_number::<i64>(&fields.concat()).map_err(|error| {
+            let column = fields
+                .first()
+                .map_or(end_column(line), |field| column_of(line, field));
+            error.at_column(column).at_line(line_number, line)
+        })
+    };
+    let long_race = Race {
+        time: joined(time_line, &times, 1)?,
+        distance: joined(distance_line, &distances, 2)?,
+    };
+    Ok(RaceSheet { races, long_race })
+}
+
+/// Multiply together the number of ways to win each race.
+pub fn part_one(races: &[Race]) -> i64 {
+    let mut product: i64 = 1;
+    for race in races {
+        product *= race.number_winning_strategies();
+    }
+    debug!("part one: {}", product);
+    product
+}
+
+/// The number of ways to win the one long race.
+pub fn part_two(long_race: &Race) -> i64 {
+    let ways = long_race.number_winning_strategies();
+    debug!("part two: {}", ways);
+    ways
+}
+
+/// `size` races, but no more than four, lasting 7 to 99 milliseconds with a
+/// record between half the best distance and the best. Any more races and
+/// part two's single race would not fit in an `i64`; the races are picked
+/// again until that race can be won too.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let best_distance = |time: i64| (time / 2) * (time - time / 2);
+    loop {
+        let races: Vec<Race> = (0..size.clamp(1, 4))
+            .map(|_| {
+                let time = rng.gen_range(7..=99);
+                let best = best_distance(time);
+                Race {
+                    time,
+                    distance: rng.gen_range(best / 2..best),
+                }
+            })
+            .collect();
+        let mut times = String::from("Time:    ");
+        let mut distances = String::from("Distance:");
+        for race in &races {
+            let width = race
+                .distance
+                .to_string()
+                .len()
+                .max(race.time.to_string().len());
+            times.push_str(&format!("  {:>width$}", race.time));
+            distances.push_str(&format!("  {:>width$}", race.distance));
+        }
+        let text = format!("{}\n{}\n", times, distances);
+        let lines: Vec<&str> = text.lines().collect();
+        let joined = get_race_sheet(&lines)
+            .expect("the generated races parse")
+            .long_race;
+        if joined.distance < best_distance(joined.time) {
+            return text;
+        }
+    }
+}
+
+/// Solves day 6 through the [`Solution`] trait.
+pub struct DaySix;
+
+impl Solution for DaySix {
+    type Input<'a> = RaceSheet;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_race_sheet(lines)
+    }
+
+    fn part_one(race_sheet: &Self::Input<'_>) -> Answer {
+        part_one(&race_sheet.races).into()
+    }
+
+    fn part_two(race_sheet: &Self::Input<'_>) -> Answer {
+        part_two(&race_sheet.long_race).into()
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_six_test_input.txt").unwrap();
+    let race_sheet = get_race_sheet(&input.lines()).unwrap();
+    assert_eq!(part_one(&race_sheet.races), 288);
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_six_test_input.txt").unwrap();
+    let race_sheet = get_race_sheet(&input.lines()).unwrap();
+    assert_eq!(part_two(&race_sheet.long_race), 71503);
+}
+
+#[test]
+fn test_race_sheet_from_malformed_lines() {
+    let error = get_race_sheet(&["garbage line"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:1: expected the line to start with `Time:`"
+    );
+    let error = get_race_sheet(&["Time: 7 15"]).unwrap_err();
+    assert_eq!(error.to_string(), "expected a `Distance:` line");
+    let error = get_race_sheet(&["Time: 7 15", "Distance: 9"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2: expected 2 distances, one for each time, found 1"
+    );
+    let error = get_race_sheet(&["Time: 7 1x", "Distance: 9 40"]).unwrap_err();
+    assert_eq!(error.to_string(), "line 1:9: expected a number, found `1x`");
+}
@@ -1,17 +1,35 @@
+//! Day 7: Camel Cards
+//!
+//! Hands of five cards are ranked by type and then card by card; in part two
+//! `J` is a joker that counts as whichever card makes the best hand.
+
 use lazy_static::lazy_static;
+use log::debug;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
 use std::collections::HashMap;
 
-use advent2023::advent_utils::get_lines_from_filepath;
+use crate::advent_utils::{column_of, end_column, parse_lines, parse_number_at};
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+use proptest::prelude::*;
+#[cfg(test)]
+use std::cmp::Ordering;
 
+/// A hand and its bid, ordered from weakest to strongest.
 #[derive(Debug, Clone)]
 pub struct CamelCardHand {
-    cards: String,
-    bid: i64,
+    pub cards: String,
+    pub bid: i64,
 }
+/// A hand and its bid where `J` is a joker, ordered from weakest to strongest.
 #[derive(Debug, Clone)]
 pub struct CamelCardHandJoker {
-    cards: String,
-    bid: i64,
+    pub cards: String,
+    pub bid: i64,
 }
 
 lazy_static! {
@@ -87,13 +105,13 @@ impl Ord for CamelCardHand {
             .iter()
             .map(|(&key, &val)| (key, val))
             .collect::<Vec<(char, i64)>>();
-        self_cards_counter.sort_by(|a, b| b.1.cmp(&a.1));
+        self_cards_counter.sort_by_key(|card_count| std::cmp::Reverse(card_count.1));
         let mut other_cards_counter: Vec<(char, i64)> = other
             .cards_counter()
             .iter()
             .map(|(&key, &val)| (key, val))
             .collect::<Vec<(char, i64)>>();
-        other_cards_counter.sort_by(|a, b| b.1.cmp(&a.1));
+        other_cards_counter.sort_by_key(|card_count| std::cmp::Reverse(card_count.1));
         // for i in 0..std::cmp::max(self_cards_counter.len(), other_cards_counter.len()) {
         //     if self_cards_counter[i].1 > other_cards_counter[i].1 {
         //         return std::cmp::Ordering::Greater;
@@ -103,18 +121,22 @@ impl Ord for CamelCardHand {
         //         continue;
         //     }
         // }
-        if self_cards_counter[0].1 > other_cards_counter[0].1 {
+        let self_first = self_cards_counter.first().map(|card_count| card_count.1);
+        let other_first = other_cards_counter.first().map(|card_count| card_count.1);
+        if self_first > other_first {
             return std::cmp::Ordering::Greater;
-        } else if self_cards_counter[0].1 < other_cards_counter[0].1 {
+        } else if self_first < other_first {
             return std::cmp::Ordering::Less;
-        } else if self_cards_counter[1].1 > other_cards_counter[1].1 {
+        }
+        // five of a kind has no second group
+        let self_second = self_cards_counter.get(1).map(|card_count| card_count.1);
+        let other_second = other_cards_counter.get(1).map(|card_count| card_count.1);
+        if self_second > other_second {
             return std::cmp::Ordering::Greater;
-        } else if self_cards_counter[1].1 < other_cards_counter[1].1 {
+        } else if self_second < other_second {
             return std::cmp::Ordering::Less;
         }
-        for i in 0..self.cards.chars().count() {
-            let self_card: char = self.cards.chars().nth(i).unwrap();
-            let other_card: char = other.cards.chars().nth(i).unwrap();
+        for (self_card, other_card) in self.cards.chars().zip(other.cards.chars()) {
             if CARD_ORDER_MAP.get(&self_card) < CARD_ORDER_MAP.get(&other_card) {
                 return std::cmp::Ordering::Greater;
             } else if CARD_ORDER_MAP.get(&self_card) > CARD_ORDER_MAP.get(&other_card) {
@@ -123,7 +145,8 @@ impl Ord for CamelCardHand {
                 continue;
             }
         }
-        std::cmp::Ordering::Equal
+        // only hands that are not five cards long can get this far unequal
+        self.cards.len().cmp(&other.cards.len())
     }
 }
 
@@ -144,7 +167,7 @@ impl CamelCardHandJoker {
                 .filter(|(&key, _)| key != 'J')
                 .map(|(_, &val)| val)
                 .collect();
-            if max_count == &jack_count && card_values.len() > 0 {
+            if max_count == &jack_count && !card_values.is_empty() {
                 max_count = card_values.iter().max().unwrap();
             }
             let mut key_to_update: Option<char> = None;
@@ -187,25 +210,26 @@ impl Ord for CamelCardHandJoker {
             .filter(|(&key, &_)| key != 'J')
             .map(|(&key, &val)| (key, val))
             .collect::<Vec<(char, i64)>>();
-        self_cards_counter.sort_by(|a, b| b.1.cmp(&a.1));
+        self_cards_counter.sort_by_key(|card_count| std::cmp::Reverse(card_count.1));
         let mut other_cards_counter: Vec<(char, i64)> = other
             .cards_counter()
             .iter()
             .filter(|(&key, &_)| key != 'J')
             .map(|(&key, &val)| (key, val))
             .collect::<Vec<(char, i64)>>();
-        other_cards_counter.sort_by(|a, b| b.1.cmp(&a.1));
+        other_cards_counter.sort_by_key(|card_count| std::cmp::Reverse(card_count.1));
 
-        if self_cards_counter.len() == 0 || other_cards_counter.len() == 0 {
-            if self_cards_counter.len() == 0 && other_cards_counter.len() == 0 {
-                return std::cmp::Ordering::Equal;
-            } else if self_cards_counter.len() == 0 {
+        if self_cards_counter.is_empty() || other_cards_counter.is_empty() {
+            if self_cards_counter.is_empty() && other_cards_counter.is_empty() {
+                // nothing but jokers, so only the number of them can differ
+                return self.cards.len().cmp(&other.cards.len());
+            } else if self_cards_counter.is_empty() {
                 if other_cards_counter[0].1 == 5 {
                     return std::cmp::Ordering::Less;
                 } else {
                     return std::cmp::Ordering::Greater;
                 }
-            } else if other_cards_counter.len() == 0 {
+            } else if other_cards_counter.is_empty() {
                 if self_cards_counter[0].1 == 5 {
                     return std::cmp::Ordering::Greater;
                 } else {
@@ -224,9 +248,7 @@ impl Ord for CamelCardHandJoker {
         } else if self_cards_counter[1].1 < other_cards_counter[1].1 {
             return std::cmp::Ordering::Less;
         }
-        for i in 0..self.cards.chars().count() {
-            let self_card: char = self.cards.chars().nth(i).unwrap();
-            let other_card: char = other.cards.chars().nth(i).unwrap();
+        for (self_card, other_card) in self.cards.chars().zip(other.cards.chars()) {
             if CARD_ORDER_MAP_JOKER.get(&self_card) < CARD_ORDER_MAP_JOKER.get(&other_card) {
                 return std::cmp::Ordering::Greater;
             } else if CARD_ORDER_MAP_JOKER.get(&self_card) > CARD_ORDER_MAP_JOKER.get(&other_card) {
@@ -235,62 +257,137 @@ impl Ord for CamelCardHandJoker {
                 continue;
             }
         }
-        std::cmp::Ordering::Equal
+        // only hands that are not five cards long can get this far unequal
+        self.cards.len().cmp(&other.cards.len())
     }
 }
 
-fn get_hands(lines: &[String]) -> Vec<CamelCardHand> {
-    let mut hands: Vec<CamelCardHand> = Vec::new();
-    for line in lines {
+impl CamelCardHand {
+    /// Parse a `cards bid` line like `32T3K 765`, checking there are five
+    /// cards and every one is a card the game has.
+    pub fn from_line(line: &str) -> Result<CamelCardHand, AocError> {
         let mut split_line = line.split_whitespace();
-        let cards: String = split_line.next().unwrap().to_string();
-        let bid: i64 = split_line.next().unwrap().parse::<i64>().unwrap();
-        hands.push(CamelCardHand {
-            cards: cards,
-            bid: bid,
-        });
+        let (Some(cards), Some(bid)) = (split_line.next(), split_line.next()) else {
+            return Err(
+                AocError::parse("expected five cards and a bid").at_column(end_column(line))
+            );
+        };
+        if let Some(extra) = split_line.next() {
+            return Err(
+                AocError::parse("expected nothing after the bid").at_column(column_of(line, extra))
+            );
+        }
+        if let Some((index, card)) = cards
+            .char_indices()
+            .find(|(_, card)| !CARD_ORDER_MAP.contains_key(card))
+        {
+            return Err(
+                AocError::parse(format!("expected a card, found `{}`", card))
+                    .at_column(column_of(line, &cards[index..])),
+            );
+        }
+        if cards.len() != 5 {
+            return Err(
+                AocError::parse(format!("expected five cards, found {}", cards.len()))
+                    .at_column(column_of(line, cards)),
+            );
+        }
+        Ok(CamelCardHand {
+            cards: cards.to_string(),
+            bid: parse_number_at(line, bid)?,
+        })
     }
-    hands
 }
 
-fn get_hands_jokers(lines: &[String]) -> Vec<CamelCardHandJoker> {
-    let mut hands: Vec<CamelCardHandJoker> = Vec::new();
-    for line in lines {
-        let mut split_line = line.split_whitespace();
-        let cards: String = split_line.next().unwrap().to_string();
-        let bid: i64 = split_line.next().unwrap().parse::<i64>().unwrap();
-        hands.push(CamelCardHandJoker {
-            cards: cards,
-            bid: bid,
-        });
+impl From<&CamelCardHand> for CamelCardHandJoker {
+    fn from(hand: &CamelCardHand) -> Self {
+        CamelCardHandJoker {
+            cards: hand.cards.clone(),
+            bid: hand.bid,
+        }
     }
-    hands
 }
 
-fn part_one(lines: &[String]) {
+/// Parse each `cards bid` line.
+pub fn get_hands(lines: &[&str]) -> Result<Vec<CamelCardHand>, AocError> {
+    parse_lines(lines, CamelCardHand::from_line)
+}
+
+/// Parse each `cards bid` line, treating `J` as a joker.
+pub fn get_hands_jokers(lines: &[&str]) -> Result<Vec<CamelCardHandJoker>, AocError> {
+    Ok(get_hands(lines)?
+        .iter()
+        .map(CamelCardHandJoker::from)
+        .collect())
+}
+
+/// Total winnings: each bid times the rank of its hand.
+pub fn part_one(hands: &[CamelCardHand]) -> i64 {
     let mut score: i64 = 0;
-    let mut hands: Vec<CamelCardHand> = get_hands(&lines);
-    hands.sort_by(|a, b| a.cmp(b));
+    let mut hands: Vec<CamelCardHand> = hands.to_vec();
+    hands.sort();
     for (i, hand) in hands.iter().enumerate() {
         score += hand.bid * (i as i64 + 1);
     }
-    println!("{}", score);
+    debug!("total winnings: {}", score);
+    score
 }
 
-fn part_two(lines: &[String]) {
+/// Total winnings with jokers.
+pub fn part_two(hands: &[CamelCardHand]) -> i64 {
     let mut score: i64 = 0;
-    let mut hands: Vec<CamelCardHandJoker> = get_hands_jokers(&lines);
-    hands.sort_by(|a, b| a.cmp(b));
+    let mut hands: Vec<CamelCardHandJoker> = hands.iter().map(CamelCardHandJoker::from).collect();
+    hands.sort();
     for (i, hand) in hands.iter().enumerate() {
         score += hand.bid * (i as i64 + 1);
     }
-    println!("{}", score);
+    debug!("total winnings: {}", score);
+    score
+}
+
+/// `size` different hands with bids from 1 to 1000. Each hand is dealt from
+/// one to five kinds of card, so every type of hand turns up, and when there
+/// are at least two hands one of them is `JJJJJ`.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let cards: Vec<char> = "AKQJT98765432".chars().collect();
+    let mut hands: Vec<String> = Vec::new();
+    if size >= 2 {
+        hands.push("JJJJJ".to_string());
+    }
+    while hands.len() < size.max(1) {
+        let kind_count = rng.gen_range(1..=5);
+        let kinds: Vec<char> = cards.choose_multiple(rng, kind_count).copied().collect();
+        let hand: String = (0..5)
+            .map(|_| *kinds.choose(rng).expect("at least one kind is dealt"))
+            .collect();
+        if !hands.contains(&hand) {
+            hands.push(hand);
+        }
+    }
+    hands.shuffle(rng);
+    hands
+        .iter()
+        .map(|hand| format!("{} {}\n", hand, rng.gen_range(1..=1000)))
+        .collect()
 }
 
-fn main() {
-    let lines: Vec<String> = get_lines_from_filepath("data/day_seven_input.txt");
-    part_one(&lines);
-    part_two(&lines);
+/// Solves day 7 through the [`Solution`] trait.
+pub struct DaySeven;
+
+impl Solution for DaySeven {
+    type Input<'a> = Vec<CamelCardHand>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_hands(lines)
+    }
+
+    fn part_one(hands: &Self::Input<'_>) -> Answer {
+        part_one(hands).into()
+    }
+
+    fn part_two(hands: &Self::Input<'_>) -> Answer {
+        part_two(hands).into()
+    }
 }
 
 #[test]
@@ -381,3 +478,90 @@ fn test_camel_card_four_of_a_kind() {
     println!("{:?}, {:?}", hand_two, hand_two.cards_counter());
     assert!(hand_one > hand_two);
 }
+
+#[test]
+fn test_camel_card_five_of_a_kind() {
+    let hand = |cards: &str| CamelCardHand {
+        cards: cards.to_string(),
+        bid: 1,
+    };
+    assert!(hand("33333") > hand("22222"));
+    assert!(hand("22222") > hand("AAAAK"));
+    assert_eq!(hand("22222").cmp(&hand("22222")), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn test_hands_from_malformed_lines() {
+    let error = CamelCardHand::from_line("garbage line").unwrap_err();
+    assert_eq!(error.to_string(), "expected a card, found `g`");
+    let lines: Vec<&str> = vec!["32T3K 765", "32T3 684"];
+    let error = get_hands(&lines).unwrap_err();
+    assert_eq!(error.to_string(), "line 2:1: expected five cards, found 4");
+    let error = get_hands(&["32T3K"]).unwrap_err();
+    assert_eq!(error.to_string(), "line 1:6: expected five cards and a bid");
+    let error = get_hands(&["32T3K 7x5"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:7: expected a number, found `7x5`"
+    );
+}
+
+#[test]
+fn test_camel_card_hands_of_other_lengths() {
+    // only parsing checks for five cards, so comparing others must not panic
+    let hand = |cards: &str| CamelCardHand {
+        cards: cards.to_string(),
+        bid: 1,
+    };
+    assert!(hand("22") > hand("2"));
+    assert!(hand("") < hand("2"));
+    let joker = |cards: &str| CamelCardHandJoker {
+        cards: cards.to_string(),
+        bid: 1,
+    };
+    assert!(joker("JJ") > joker("J"));
+}
+
+/// Any five cards, as a pattern for proptest to fill in. Half are drawn from
+/// only four cards, jokers among them, so pairs, full houses and ties on type
+/// come up often.
+#[cfg(test)]
+const CARDS: &str = "[AJ2T]{5}|[AKQJT98765432]{5}";
+
+/// The laws `sort` relies on: comparing both ways gives opposite answers,
+/// only a hand compares equal to itself, and ordering is transitive.
+#[cfg(test)]
+fn assert_total_order<T: Ord>(
+    a: &T,
+    b: &T,
+    c: &T,
+    same_cards: impl Fn(&T, &T) -> bool,
+) -> Result<(), TestCaseError> {
+    prop_assert_eq!(a.cmp(b), b.cmp(a).reverse());
+    prop_assert_eq!(a.cmp(b) == Ordering::Equal, same_cards(a, b));
+    for first in [a, b, c] {
+        for second in [a, b, c] {
+            for third in [a, b, c] {
+                if first <= second && second <= third {
+                    prop_assert!(first <= third);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn test_camel_card_hand_total_order(a in CARDS, b in CARDS, c in CARDS) {
+        let hand = |cards: &String| CamelCardHand { cards: cards.clone(), bid: 1 };
+        assert_total_order(&hand(&a), &hand(&b), &hand(&c), |x, y| x.cards == y.cards)?;
+    }
+
+    #[test]
+    fn test_camel_card_hand_joker_total_order(a in CARDS, b in CARDS, c in CARDS) {
+        let hand = |cards: &String| CamelCardHandJoker { cards: cards.clone(), bid: 1 };
+        assert_total_order(&hand(&a), &hand(&b), &hand(&c), |x, y| x.cards == y.cards)?;
+    }
+}
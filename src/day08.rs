@@ -0,0 +1,578 @@
+//! Day 8: Haunted Wasteland
+//!
+//! Follow the left/right instructions through a network of nodes, either
+//! from `AAA` to `ZZZ` or from every `..A` node at once until all reach a
+//! `..Z` node.
+
+use std::collections::{BTreeMap, HashMap};
+
+use log::debug;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::advent_utils::{column_of, end_column};
+use crate::error::AocError;
+use crate::generate::unique_names;
+use crate::graph::bfs;
+#[cfg(test)]
+use crate::input::Input;
+use crate::math::lcm_of;
+use crate::solution::{Answer, Solution};
+use crate::stepper::{Playback, Simulation, Stepper};
+
+/// The two nodes reachable from a node, like `(BBB, CCC)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Node<'a> {
+    pub left: &'a str,
+    pub right: &'a str,
+}
+
+impl<'a> Node<'a> {
+    /// Parse a line like `AAA = (BBB, CCC)` into the node's name and where it leads.
+    pub fn from_line(line: &'a str) -> Result<(&'a str, Node<'a>), AocError> {
+        let (key, branches) = line.split_once('=').ok_or_else(|| {
+            AocError::parse("expected `=` after the node name").at_column(end_column(line))
+        })?;
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(AocError::parse("expected a node name before `=`").at_column(1));
+        }
+        let branches = branches.trim();
+        let inner = branches
+            .strip_prefix('(')
+            .and_then(|branches| branches.strip_suffix(')'))
+            .ok_or_else(|| {
+                AocError::parse("expected the branches wrapped in `(` and `)`")
+                    .at_column(column_of(line, branches))
+            })?;
+        let (left, right) = inner.split_once(',').ok_or_else(|| {
+            AocError::parse("expected `,` between the left and right branches")
+                .at_column(column_of(line, inner))
+        })?;
+        let (left, right) = (left.trim(), right.trim());
+        for (branch, side) in [(left, "left"), (right, "right")] {
+            if branch.is_empty() {
+                return Err(AocError::parse(format!("expected a {} branch", side))
+                    .at_column(column_of(line, inner)));
+            }
+        }
+        Ok((key, Node { left, right }))
+    }
+}
+
+/// The `L`/`R` instructions and the network they are followed through.
+/// Every branch leads to a node the network has.
+#[derive(Debug, Clone)]
+pub struct Map<'a> {
+    pub directions: Vec<char>,
+    pub network: HashMap<&'a str, Node<'a>>,
+}
+
+/// Parse the instructions on the first line and the nodes on every non-blank
+/// line after it.
+pub fn get_map<'a>(lines: &[&'a str]) -> Result<Map<'a>, AocError> {
+    let Some((first_line, node_lines)) = lines.split_first() else {
+        return Err(AocError::parse(
+            "expected a line of `L` and `R` instructions",
+        ));
+    };
+    let directions: Vec<char> = first_line.trim_end().chars().collect();
+    if directions.is_empty() {
+        return Err(
+            AocError::parse("expected a line of `L` and `R` instructions").at_line(1, first_line),
+        );
+    }
+    if let Some(index) = directions.iter().position(|c| *c != 'L' && *c != 'R') {
+        let error = AocError::parse(format!(
+            "expected `L` or `R`, found `{}`",
+            directions[index]
+        ));
+        return Err(error.at_column(index + 1).at_line(1, first_line));
+    }
+    let mut network: HashMap<&str, Node> = HashMap::new();
+    let mut node_lines_by_key: Vec<(usize, &str, Node)> = Vec::new();
+    for (index, line) in node_lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_number = index + 2;
+        let (key, node) =
+            Node::from_line(line).map_err(|error| error.at_line(line_number, line))?;
+        if network.insert(key, node).is_some() {
+            let error = AocError::parse(format!("node `{}` is listed twice", key));
+            return Err(error
+                .at_column(column_of(line, key))
+                .at_line(line_number, line));
+        }
+        node_lines_by_key.push((line_number, line, node));
+    }
+    for (line_number, line, node) in node_lines_by_key {
+        for branch in [node.left, node.right] {
+            if !network.contains_key(branch) {
+                let error = AocError::parse(format!("unknown node `{}`", branch));
+                return Err(error
+                    .at_column(column_of(line, branch))
+                    .at_line(line_number, line));
+            }
+        }
+    }
+    Ok(Map {
+        directions,
+        network,
+    })
+}
+
+/// Every node whose name ends in `A`.
+pub fn get_starting_keys_from_hashmap<'a>(hashmap: &HashMap<&'a str, Node>) -> Vec<&'a str> {
+    let mut starting_keys: Vec<&str> = Vec::new();
+    for key in hashmap.keys() {
+        if key.ends_with("A") {
+            starting_keys.push(key);
+        }
+    }
+    starting_keys
+}
+
+/// Steps from `AAA` to `ZZZ`, or `None` if there is no `AAA` or the
+/// instructions never reach `ZZZ` from it.
+pub fn part_one(map: &Map) -> Option<i64> {
+    let step_count = count_steps(map, "AAA", |key| key == "ZZZ");
+    debug!("step_count: {:?}", step_count);
+    step_count
+}
+
+fn get_new_keys_from_hashmap<'a>(
+    hashmap: &HashMap<&str, Node<'a>>,
+    keys: &[&str],
+    direction: &char,
+) -> Vec<&'a str> {
+    let mut new_keys: Vec<&str> = Vec::new();
+    for key in keys {
+        let node: Node = hashmap[key];
+        if direction == &'L' {
+            new_keys.push(node.left)
+        } else {
+            new_keys.push(node.right)
+        }
+    }
+    new_keys
+}
+
+fn check_keys_end_with_z(keys: &[&str]) -> bool {
+    keys.iter().all(|x| x.ends_with("Z"))
+}
+
+/// Steps from `key` until the first node `is_end` accepts, or `None` if there
+/// is no such node as `key` or the walk goes round without reaching an end.
+fn count_steps(map: &Map, key: &str, is_end: impl Fn(&str) -> bool) -> Option<i64> {
+    let mut key = *map.network.get_key_value(key)?.0;
+    // after a step from every node at every point in the instructions, the
+    // walk can only be going round a loop it has already been round
+    let limit = map.network.len() * map.directions.len();
+    for (step_count, direction) in map.directions.iter().cycle().take(limit).enumerate() {
+        let node: Node = map.network[key];
+        if direction == &'L' {
+            key = node.left;
+        } else {
+            key = node.right;
+        }
+        if is_end(key) {
+            return Some(step_count as i64 + 1);
+        }
+    }
+    None
+}
+
+/// Steps from `key` until the first node whose name ends in `Z`, or `None` if
+/// it never gets to one.
+pub fn count_steps_for_key(key: &str, map: &Map) -> Option<i64> {
+    count_steps(map, key, |key| key.ends_with("Z"))
+}
+
+/// Steps until every `..A` node reaches a `..Z` node at once, walking them all in lockstep.
+///
+/// This is the straightforward simulation and does not finish on the real input;
+/// see [`part_two_optimized`].
+pub fn part_two(map: &Map) -> i64 {
+    let mut step_count: i64 = 0;
+    let mut keys = get_starting_keys_from_hashmap(&map.network);
+    for direction in map.directions.iter().cycle() {
+        step_count += 1;
+        keys = get_new_keys_from_hashmap(&map.network, &keys, direction);
+        if check_keys_end_with_z(&keys) {
+            break;
+        }
+    }
+    debug!("step_count: {}", step_count);
+    step_count
+}
+
+/// Steps until every `..A` node reaches a `..Z` node at once, as the least
+/// common multiple of each path's own length, or `None` if some path never
+/// reaches a `..Z` node or the answer does not fit in an `i64`.
+pub fn part_two_optimized(map: &Map) -> Option<i64> {
+    let keys = get_starting_keys_from_hashmap(&map.network);
+    debug!("# keys: {}", keys.len());
+    let step_array = keys
+        .iter()
+        .map(|key| count_steps_for_key(key, map))
+        .collect::<Option<Vec<i64>>>()?;
+    let lcm = lcm_of(&step_array);
+    debug!("lcm: {:?}", lcm);
+    lcm
+}
+
+/// Every ghost walking the network in lockstep, as in [`part_two`], for the stepper.
+pub struct GhostWalk<'a> {
+    network: HashMap<&'a str, Node<'a>>,
+    directions: Vec<char>,
+    /// The `..A` nodes the ghosts start from, in order.
+    starts: Vec<&'a str>,
+}
+
+impl<'a> GhostWalk<'a> {
+    pub fn new(map: &Map<'a>) -> GhostWalk<'a> {
+        let mut starts = get_starting_keys_from_hashmap(&map.network);
+        starts.sort_unstable();
+        GhostWalk {
+            network: map.network.clone(),
+            directions: map.directions.clone(),
+            starts,
+        }
+    }
+}
+
+/// Where every ghost is after some number of steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ghosts<'a> {
+    pub steps: usize,
+    pub keys: Vec<&'a str>,
+}
+
+impl<'a> Simulation for GhostWalk<'a> {
+    type State = Ghosts<'a>;
+
+    fn initial(&self) -> Ghosts<'a> {
+        Ghosts {
+            steps: 0,
+            keys: self.starts.clone(),
+        }
+    }
+
+    fn step(&self, ghosts: &Ghosts<'a>) -> Option<Ghosts<'a>> {
+        if check_keys_end_with_z(&ghosts.keys) || self.directions.is_empty() {
+            return None;
+        }
+        let direction = self.directions[ghosts.steps % self.directions.len()];
+        Some(Ghosts {
+            steps: ghosts.steps + 1,
+            keys: get_new_keys_from_hashmap(&self.network, &ghosts.keys, &direction),
+        })
+    }
+
+    fn draw(&self, ghosts: &Ghosts<'a>) -> String {
+        let mut drawn = format!("steps: {}", ghosts.steps);
+        if !self.directions.is_empty() {
+            let next = self.directions[ghosts.steps % self.directions.len()];
+            drawn.push_str(&format!(", going {} next", next));
+        }
+        drawn.push('\n');
+        for (start, key) in self.starts.iter().zip(&ghosts.keys) {
+            let arrived = if key.ends_with('Z') { "  arrived" } else { "" };
+            drawn.push_str(&format!("\n{} -> {}{}", start, key, arrived));
+        }
+        drawn
+    }
+}
+
+/// A network for one to six ghosts, with instructions `size` long up to 500.
+///
+/// As in the puzzle, each ghost's path reaches its `..Z` node after a whole
+/// number of passes through the instructions and then goes round the same loop
+/// again, so every ghost is on a `..Z` node after the least common multiple of
+/// the path lengths. The first ghost goes from `AAA` to `ZZZ`. Each node's other
+/// branch, which the instructions never take from it, leads to a random node
+/// on the same path.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let length = size.clamp(1, 500);
+    let directions: Vec<char> = (0..length)
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+    let ghosts = rng.gen_range(1..=6);
+    let letters = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let starts = unique_names(rng, ghosts - 1, 2, letters, &["AA"]);
+    let ends = unique_names(rng, ghosts - 1, 2, letters, &["ZZ"]);
+    let passes: Vec<usize> = (0..ghosts).map(|_| rng.gen_range(1..=4)).collect();
+    let between: usize = passes.iter().map(|passes| passes * length - 1).sum();
+    // no node on the way can end in `A` or `Z`
+    let mut between = unique_names(rng, between, 3, &letters[1..25], &[]).into_iter();
+    let mut nodes: Vec<String> = Vec::new();
+    for ghost in 0..ghosts {
+        let mut path: Vec<String> = match ghost {
+            0 => vec!["AAA".to_string()],
+            _ => vec![format!("{}A", starts[ghost - 1])],
+        };
+        path.extend(between.by_ref().take(passes[ghost] * length - 1));
+        path.push(match ghost {
+            0 => "ZZZ".to_string(),
+            _ => format!("{}Z", ends[ghost - 1]),
+        });
+        let steps = path.len() - 1;
+        for (index, node) in path.iter().enumerate() {
+            // the end is reached at the start of the instructions, so it leads
+            // the same way as the start
+            let next = &path[if index == steps { 1 } else { index + 1 }];
+            let decoy = path.choose(rng).expect("a path has two or more nodes");
+            let (left, right) = match directions[index % length] {
+                'L' => (next, decoy),
+                _ => (decoy, next),
+            };
+            nodes.push(format!("{} = ({}, {})", node, left, right));
+        }
+    }
+    nodes.shuffle(rng);
+    let directions: String = directions.into_iter().collect();
+    format!("{}\n\n{}\n", directions, nodes.join("\n"))
+}
+
+/// How many steps a smaller network from [`shrink_input`] may take to get
+/// every ghost onto a `..Z` node, so that [`part_two`] still finishes on it.
+const SHRINK_STEP_LIMIT: usize = 100_000;
+
+/// Each node's name and the names it leads to, owned so that nodes can be
+/// renamed and dropped while shrinking.
+type Network = BTreeMap<String, (String, String)>;
+
+/// Smaller networks to try in place of `input`, for cross-checking: each has
+/// one ghost or one instruction fewer, and any nodes no ghost can reach are
+/// left out. A ghost is left out by renaming its start so that it no longer
+/// ends in `A`. Only networks every ghost gets through together within a
+/// limit of steps are offered.
+pub fn shrink_input(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Ok(map) = get_map(&lines) else {
+        return Vec::new();
+    };
+    let network: Network = map
+        .network
+        .iter()
+        .map(|(key, node)| {
+            let branches = (node.left.to_string(), node.right.to_string());
+            (key.to_string(), branches)
+        })
+        .collect();
+    let mut smaller: Vec<(Vec<char>, Network)> = Vec::new();
+    let starts: Vec<&String> = network.keys().filter(|key| key.ends_with('A')).collect();
+    if starts.len() > 1 {
+        for start in &starts {
+            let prefix = &start[..start.len() - 1];
+            let renamed = ('B'..='Y')
+                .map(|last| format!("{}{}", prefix, last))
+                .find(|name| !network.contains_key(name));
+            if let Some(renamed) = renamed {
+                smaller.push((
+                    map.directions.clone(),
+                    rename_node(&network, start, &renamed),
+                ));
+            }
+        }
+    }
+    if map.directions.len() > 1 {
+        for index in 0..map.directions.len() {
+            let mut directions = map.directions.clone();
+            directions.remove(index);
+            smaller.push((directions, network.clone()));
+        }
+    }
+    let reachable = reachable_nodes(&network);
+    if reachable.len() < network.len() {
+        smaller.push((map.directions.clone(), reachable));
+    }
+    smaller
+        .into_iter()
+        .map(|(directions, network)| network_text(&directions, &reachable_nodes(&network)))
+        .filter(|text| {
+            let lines: Vec<&str> = text.lines().collect();
+            get_map(&lines).is_ok_and(|map| ghosts_arrive_within(&map, SHRINK_STEP_LIMIT))
+        })
+        .collect()
+}
+
+/// `network` with the node `from` called `to` instead, everywhere it appears.
+fn rename_node(network: &Network, from: &str, to: &str) -> Network {
+    let rename = |key: &String| {
+        if key == from {
+            to.to_string()
+        } else {
+            key.clone()
+        }
+    };
+    network
+        .iter()
+        .map(|(key, (left, right))| (rename(key), (rename(left), rename(right))))
+        .collect()
+}
+
+/// The nodes of `network` that some `..A` node leads to, itself included.
+fn reachable_nodes(network: &Network) -> Network {
+    let starts = network.keys().filter(|key| key.ends_with('A')).cloned();
+    let search = bfs(starts, |key: &String| {
+        let (left, right) = &network[key];
+        [left.clone(), right.clone()]
+    });
+    network
+        .iter()
+        .filter(|(key, _)| search.contains(key))
+        .map(|(key, branches)| (key.clone(), branches.clone()))
+        .collect()
+}
+
+/// `directions` and `network` written out in the puzzle's format.
+fn network_text(directions: &[char], network: &Network) -> String {
+    let nodes: Vec<String> = network
+        .iter()
+        .map(|(key, (left, right))| format!("{} = ({}, {})", key, left, right))
+        .collect();
+    let directions: String = directions.iter().collect();
+    format!("{}\n\n{}\n", directions, nodes.join("\n"))
+}
+
+/// Whether every ghost is on a `..Z` node at once within `limit` steps.
+fn ghosts_arrive_within(map: &Map, limit: usize) -> bool {
+    let mut keys = get_starting_keys_from_hashmap(&map.network);
+    for direction in map.directions.iter().cycle().take(limit) {
+        keys = get_new_keys_from_hashmap(&map.network, &keys, direction);
+        if check_keys_end_with_z(&keys) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Solves day 8 through the [`Solution`] trait.
+pub struct DayEight;
+
+impl Solution for DayEight {
+    type Input<'a> = Map<'a>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_map(lines)
+    }
+
+    fn part_one(map: &Self::Input<'_>) -> Answer {
+        part_one(map).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part_two(map: &Self::Input<'_>) -> Answer {
+        part_two_optimized(map).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn stepper<'b>(map: &'b Self::Input<'_>) -> Option<Box<dyn Playback + 'b>> {
+        Some(Box::new(Stepper::new(GhostWalk::new(map))))
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_eight_test_input.txt").unwrap();
+    let map = get_map(&input.lines()).unwrap();
+    assert_eq!(part_one(&map), Some(6));
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_eight_part_two_test.txt").unwrap();
+    let map = get_map(&input.lines()).unwrap();
+    assert_eq!(part_two(&map), 6);
+}
+
+#[test]
+fn test_part_two_optimized() {
+    let input = Input::from_data_file("data/day_eight_part_two_test.txt").unwrap();
+    let map = get_map(&input.lines()).unwrap();
+    assert_eq!(part_two_optimized(&map), Some(6));
+}
+
+#[test]
+fn test_part_two_optimized_overflow() {
+    // five ghosts on loops of coprime lengths that only line up after more
+    // than i64::MAX steps
+    let mut text = String::from("L\n");
+    for (ghost, length) in [10007, 10009, 10037, 10039, 10061].into_iter().enumerate() {
+        let name = |step: usize| match step {
+            0 => format!("{}A", ghost),
+            step if step == length => format!("{}Z", ghost),
+            step => format!("{}N{}", ghost, step),
+        };
+        for step in 0..=length {
+            let next = name(if step == length { 1 } else { step + 1 });
+            text.push_str(&format!("\n{} = ({}, {})", name(step), next, next));
+        }
+    }
+    let lines: Vec<&str> = text.lines().collect();
+    let map = get_map(&lines).unwrap();
+    assert_eq!(part_two_optimized(&map), None);
+    assert_eq!(DayEight::part_two(&map), Answer::Unsolved);
+}
+
+#[test]
+fn test_ghost_walk() {
+    let input = Input::from_data_file("data/day_eight_part_two_test.txt").unwrap();
+    let map = get_map(&input.lines()).unwrap();
+    let mut stepper = Stepper::new(GhostWalk::new(&map));
+    assert_eq!(
+        stepper.frame(),
+        "steps: 0, going L next\n\n11A -> 11A\n22A -> 22A"
+    );
+    let ghosts = stepper.run(usize::MAX);
+    assert_eq!(ghosts.steps as i64, part_two(&map));
+    assert_eq!(ghosts.keys, vec!["11Z", "22Z"]);
+    assert!(stepper
+        .frame()
+        .ends_with("11A -> 11Z  arrived\n22A -> 22Z  arrived"));
+}
+
+#[test]
+fn test_map_from_malformed_lines() {
+    let error = Node::from_line("AAA (BBB, CCC)").unwrap_err();
+    assert_eq!(error.to_string(), "expected `=` after the node name");
+    let error = Node::from_line("AAA = BBB, CCC").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected the branches wrapped in `(` and `)`"
+    );
+    let error = get_map(&["garbage line"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:1: expected `L` or `R`, found `g`"
+    );
+    let lines: Vec<&str> = vec!["LR", "", "AAA = (BBB, ZZZ)", "ZZZ = (ZZZ ZZZ)"];
+    let error = get_map(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 4:8: expected `,` between the left and right branches"
+    );
+    let lines: Vec<&str> = vec!["LR", "", "AAA = (BBB, ZZZ)", "ZZZ = (ZZZ, ZZZ)"];
+    let error = get_map(&lines).unwrap_err().in_file("map.txt");
+    assert_eq!(
+        error.diagnostic(),
+        "could not parse map.txt:3:8\n  |\n3 | AAA = (BBB, ZZZ)\n  |        ^ unknown node `BBB`"
+    );
+}
+
+#[test]
+fn test_part_one_never_arrives() {
+    let lines: Vec<&str> = vec![
+        "L",
+        "",
+        "AAA = (BBB, ZZZ)",
+        "BBB = (AAA, BBB)",
+        "ZZZ = (ZZZ, ZZZ)",
+    ];
+    let map = get_map(&lines).unwrap();
+    assert_eq!(part_one(&map), None);
+    assert_eq!(part_two_optimized(&map), None);
+    let map = get_map(&["L", "", "BBB = (BBB, BBB)"]).unwrap();
+    assert_eq!(DayEight::part_one(&map), Answer::Unsolved);
+}
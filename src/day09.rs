@@ -0,0 +1,201 @@
+//! Day 9: Mirage Maintenance
+//!
+//! Extrapolate each history forwards and backwards by repeatedly taking
+//! differences until they are all zero.
+
+use log::debug;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::advent_utils::{end_column, parse_lines, parse_number_at};
+use crate::error::AocError;
+#[cfg(test)]
+use crate::input::Input;
+use crate::solution::{Answer, Solution};
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// The readings on one line of the report, of which there must be at least one.
+pub fn get_vector_from_line(line: &str) -> Result<Vec<i128>, AocError> {
+    let mut vector: Vec<i128> = Vec::new();
+    for number in line.split_whitespace() {
+        vector.push(parse_number_at(line, number)?);
+    }
+    if vector.is_empty() {
+        return Err(AocError::parse("expected at least one reading").at_column(end_column(line)));
+    }
+    Ok(vector)
+}
+
+/// Parse every history in the report.
+pub fn get_histories(lines: &[&str]) -> Result<Vec<Vec<i128>>, AocError> {
+    parse_lines(lines, get_vector_from_line)
+}
+
+/// The differences between consecutive values, one shorter than `vector`.
+pub fn get_difference_vector(vector: &[i128]) -> Vec<i128> {
+    vector.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+/// Whether every value is zero.
+pub fn check_vector_all_zeroes(vector: &[i128]) -> bool {
+    for number in vector.iter() {
+        if *number != 0_i128 {
+            return false;
+        }
+    }
+    true
+}
+
+/// Extrapolate the value after the end of `vector`.
+pub fn get_next_value_from_vector(vector: &[i128]) -> i128 {
+    let mut difference_vectors: Vec<Vec<i128>> = Vec::new();
+    let mut difference_vector: Vec<i128> = get_difference_vector(vector);
+    while !check_vector_all_zeroes(&difference_vector) {
+        difference_vectors.push(difference_vector.clone());
+        difference_vector = get_difference_vector(&difference_vector);
+    }
+    let mut next_value: i128 = vector[vector.len() - 1];
+    for difference_vector in difference_vectors.iter() {
+        next_value += difference_vector[difference_vector.len() - 1];
+    }
+    next_value
+}
+
+/// Extrapolate the value before the start of `vector`.
+pub fn get_previous_value_from_vector(vector: &[i128]) -> i128 {
+    let mut difference_vectors: Vec<Vec<i128>> = Vec::new();
+    let mut difference_vector: Vec<i128> = get_difference_vector(vector);
+    while !check_vector_all_zeroes(&difference_vector) {
+        difference_vectors.push(difference_vector.clone());
+        difference_vector = get_difference_vector(&difference_vector);
+    }
+    let mut next_value: i128 = 0;
+    for difference_vector in difference_vectors.iter().rev() {
+        next_value = difference_vector[0] - next_value;
+    }
+    next_value = vector[0] - next_value;
+
+    next_value
+}
+
+/// Sum the next value of every history.
+pub fn part_one(histories: &[Vec<i128>]) -> i128 {
+    let mut sum: i128 = 0;
+    for vector in histories {
+        sum += get_next_value_from_vector(vector);
+    }
+    debug!("sum of extrapolated values: {}", sum);
+    sum
+}
+
+/// Sum the previous value of every history.
+pub fn part_two(histories: &[Vec<i128>]) -> i128 {
+    let mut sum: i128 = 0;
+    for vector in histories {
+        let previous_value = get_previous_value_from_vector(vector);
+        sum += previous_value;
+    }
+    debug!("sum of extrapolated values: {}", sum);
+    sum
+}
+
+/// `size` histories, each 21 values of a polynomial of degree up to 6 with
+/// coefficients from -5 to 5, so that taking differences always reaches zero.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size.max(1) {
+        let coefficients: Vec<i128> = (0..=rng.gen_range(0..=6))
+            .map(|_| rng.gen_range(-5..=5))
+            .collect();
+        let offset: i128 = rng.gen_range(-10..=10);
+        let values: Vec<String> = (0..21)
+            .map(|x| {
+                let x = x + offset;
+                let value = coefficients.iter().rev().fold(0, |value, c| value * x + c);
+                value.to_string()
+            })
+            .collect();
+        text.push_str(&values.join(" "));
+        text.push('\n');
+    }
+    text
+}
+
+/// Solves day 9 through the [`Solution`] trait.
+pub struct DayNine;
+
+impl Solution for DayNine {
+    type Input<'a> = Vec<Vec<i128>>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_histories(lines)
+    }
+
+    fn part_one(histories: &Self::Input<'_>) -> Answer {
+        part_one(histories).into()
+    }
+
+    fn part_two(histories: &Self::Input<'_>) -> Answer {
+        part_two(histories).into()
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_nine_test.txt").unwrap();
+    let histories = get_histories(&input.lines()).unwrap();
+    assert_eq!(part_one(&histories), 114);
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_nine_test.txt").unwrap();
+    let histories = get_histories(&input.lines()).unwrap();
+    assert_eq!(part_two(&histories), 2);
+}
+
+#[test]
+fn test_histories_from_malformed_lines() {
+    let error = get_histories(&["0 3 6", "garbage line"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2:1: expected a number, found `garbage`"
+    );
+    let error = get_histories(&["0 3 6", "  "]).unwrap_err();
+    assert_eq!(error.to_string(), "line 2:3: expected at least one reading");
+}
+
+#[test]
+fn test_extrapolate_short_histories() {
+    // differences that run out before reaching all zeroes still extrapolate
+    assert_eq!(get_next_value_from_vector(&[1, 2, 4]), 7);
+    assert_eq!(get_previous_value_from_vector(&[1, 2, 4]), 1);
+    assert_eq!(get_next_value_from_vector(&[5]), 5);
+    assert_eq!(get_previous_value_from_vector(&[5]), 5);
+}
+
+/// The polynomial with `coefficients`, lowest power first, at `x`.
+#[cfg(test)]
+fn polynomial(coefficients: &[i128], x: i128) -> i128 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0, |value, coefficient| value * x + coefficient)
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn test_extrapolation_is_exact_for_polynomials(
+        coefficients in prop::collection::vec(-10_i128..=10, 1..=6),
+        extra in 1_usize..5,
+    ) {
+        // a polynomial of degree d is pinned down by d + 2 values, the last
+        // difference being all zeroes
+        let length = (coefficients.len() + extra) as i128;
+        let history: Vec<i128> = (0..length).map(|x| polynomial(&coefficients, x)).collect();
+        prop_assert_eq!(get_next_value_from_vector(&history), polynomial(&coefficients, length));
+        prop_assert_eq!(get_previous_value_from_vector(&history), polynomial(&coefficients, -1));
+    }
+}
@@ -0,0 +1,351 @@
+//! Day 10: Pipe Maze
+//!
+//! Find the loop of pipe that runs through the animal's starting tile `S`.
+//! Coordinates are [`Point`]s with `y` counting lines down from the top.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::error::AocError;
+use crate::generate::{loop_tiles, rectilinear_loop, weighted};
+use crate::geometry::interior_points;
+use crate::grid::Grid;
+#[cfg(test)]
+use crate::input::Input;
+use crate::point::{Direction, Point};
+use crate::render::{Image, Palette, BLACK, GREY, RED, YELLOW};
+use crate::solution::{Answer, Solution};
+use crate::stepper::{Playback, Simulation, Stepper};
+
+/// Every tile marked `S`.
+pub fn find_s_coordinates(maze: &Grid<char>) -> Vec<Point> {
+    maze.iter()
+        .filter(|(_, c)| **c == 'S')
+        .map(|(position, _)| Point::from(position))
+        .collect()
+}
+
+/// The way out of `pipe` for something travelling in `heading`, or `None` if
+/// the pipe does not connect to the side it came in from.
+pub fn pipe_exit(pipe: char, heading: Direction) -> Option<Direction> {
+    match (pipe, heading) {
+        ('|', Direction::Up | Direction::Down) => Some(heading),
+        ('-', Direction::Left | Direction::Right) => Some(heading),
+        ('L', Direction::Down) => Some(Direction::Right),
+        ('L', Direction::Left) => Some(Direction::Up),
+        ('7', Direction::Up) => Some(Direction::Left),
+        ('7', Direction::Right) => Some(Direction::Down),
+        ('F', Direction::Up) => Some(Direction::Right),
+        ('F', Direction::Left) => Some(Direction::Down),
+        ('J', Direction::Down) => Some(Direction::Left),
+        ('J', Direction::Right) => Some(Direction::Up),
+        _ => None,
+    }
+}
+
+/// The tile after the last one of `path`, which holds at least two tiles, or
+/// `None` if the path is back at `S` or its pipe does not connect.
+pub fn next_path_tile(path: &[Point], maze: &Grid<char>) -> Option<Point> {
+    let current = path[path.len() - 1];
+    let &pipe = maze.get(current.x, current.y)?;
+    let heading = Direction::from_offset(current - path[path.len() - 2])?;
+    let exit = pipe_exit(pipe, heading)?;
+    Some(current.step(exit))
+}
+
+/// Extend `path`, which holds at least two tiles, by following the pipes from
+/// its last tile until it returns to `S` or reaches a pipe that does not connect.
+pub fn get_path(mut path: Vec<Point>, maze: &Grid<char>) -> Vec<Point> {
+    match next_path_tile(&path, maze) {
+        Some(next) => {
+            path.push(next);
+            get_path(path, maze)
+        }
+        None => path,
+    }
+}
+
+/// The tiles of the longest loop through `S`, starting and ending with `S`,
+/// or `None` if there is no `S` or no loop through it.
+pub fn get_loop(maze: &Grid<char>) -> Option<Vec<Point>> {
+    let start = *find_s_coordinates(maze).first()?;
+    let mut paths: Vec<Vec<Point>> = Vec::new();
+    for next in start.neighbours() {
+        let mut path: Vec<Point> = vec![start, next];
+        match maze.get(next.x, next.y) {
+            None => continue,
+            Some('.') => {}
+            Some(_) => path = get_path(path, maze),
+        }
+        if path[0] == path[path.len() - 1] {
+            paths.push(path);
+        }
+    }
+    paths.into_iter().max_by_key(Vec::len)
+}
+
+/// Steps along the loop to the tile farthest from the start, or `None` if
+/// there is no loop.
+pub fn part_one(maze: &Grid<char>) -> Option<i128> {
+    get_loop(maze).map(|tiles| tiles.len() as i128 / 2)
+}
+
+/// How many tiles the loop encloses, or `None` if there is no loop. The loop
+/// is a polygon through the centres of its tiles, so by Pick's theorem these
+/// are its interior points.
+pub fn part_two(maze: &Grid<char>) -> Option<i128> {
+    get_loop(maze).map(|tiles| interior_points(&tiles))
+}
+
+/// Parse the maze, checking every tile is a pipe, ground or the one `S`.
+pub fn get_maze(lines: &[&str]) -> Result<Grid<char>, AocError> {
+    let maze = Grid::parse_with(lines, |tile| match tile {
+        '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S' => Ok(tile),
+        _ => Err(AocError::parse(format!(
+            "expected a pipe, `.` or `S`, found `{}`",
+            tile
+        ))),
+    })?;
+    match find_s_coordinates(&maze)[..] {
+        [] => Err(AocError::parse("expected an `S` where the animal starts")),
+        [_] => Ok(maze),
+        [_, second, ..] => {
+            let y = second.y as usize;
+            Err(AocError::parse("expected only one `S`")
+                .at_column(second.x as usize + 1)
+                .at_line(y + 1, lines[y]))
+        }
+    }
+}
+
+/// The maze with its pipes in grey, `S` in red and the loop highlighted.
+pub fn render(maze: &Grid<char>) -> Image {
+    let palette = Palette::new(GREY).with(".", BLACK).with("S", RED);
+    let mut image = Image::from_grid(maze, &palette);
+    image.overlay(get_loop(maze).unwrap_or_default(), YELLOW);
+    image
+}
+
+/// The pipe a tile shows as in the stepper, drawn with box-drawing characters.
+fn pipe_glyph(pipe: char) -> char {
+    match pipe {
+        '|' => '│',
+        '-' => '─',
+        'L' => '└',
+        'J' => '┘',
+        '7' => '┐',
+        'F' => '┌',
+        other => other,
+    }
+}
+
+/// Following the pipes out of `S` one tile at a time, as [`get_path`] does,
+/// for the stepper.
+pub struct LoopWalk<'a> {
+    pub maze: &'a Grid<char>,
+}
+
+impl Simulation for LoopWalk<'_> {
+    type State = Vec<Point>;
+
+    /// `S` and the first neighbour whose pipe leads back into it.
+    fn initial(&self) -> Vec<Point> {
+        let Some(&start) = find_s_coordinates(self.maze).first() else {
+            return Vec::new();
+        };
+        let first = Direction::ALL.into_iter().find_map(|direction| {
+            let next = start.step(direction);
+            let &pipe = self.maze.get(next.x, next.y)?;
+            pipe_exit(pipe, direction).map(|_| next)
+        });
+        [Some(start), first].into_iter().flatten().collect()
+    }
+
+    fn step(&self, path: &Vec<Point>) -> Option<Vec<Point>> {
+        if path.len() < 2 {
+            return None;
+        }
+        let next = next_path_tile(path, self.maze)?;
+        let mut path = path.clone();
+        path.push(next);
+        Some(path)
+    }
+
+    fn draw(&self, path: &Vec<Point>) -> String {
+        let mut drawn = self.maze.map(|_| ' ');
+        for tile in path {
+            if let Some(cell) = drawn.get_mut(tile.x, tile.y) {
+                *cell = pipe_glyph(self.maze[(tile.x as usize, tile.y as usize)]);
+            }
+        }
+        if let Some(head) = path.last() {
+            if let Some(cell) = drawn.get_mut(head.x, head.y) {
+                *cell = '@';
+            }
+        }
+        format!("{}\n\npath: {} tiles", drawn, path.len())
+    }
+}
+
+/// A maze with one loop through `S`, the outline of a [`rectilinear_loop`]
+/// with `size` columns, in a field of random pipes and ground. Tiles next to
+/// `S` that are not on the loop are ground, so that no other pipes connect to
+/// it.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let vertices = rectilinear_loop(rng, size, 4);
+    let margin = Point::new(rng.gen_range(0..=2), rng.gen_range(0..=2));
+    let tiles: Vec<Point> = loop_tiles(&vertices)
+        .into_iter()
+        .map(|tile| tile + margin)
+        .collect();
+    let width = tiles.iter().map(|tile| tile.x).max().unwrap_or(0) + rng.gen_range(1..=3);
+    let height = tiles.iter().map(|tile| tile.y).max().unwrap_or(0) + rng.gen_range(1..=3);
+    let mut maze = Grid::new(width as usize, height as usize, '.');
+    let junk = [
+        ('.', 6),
+        ('|', 1),
+        ('-', 1),
+        ('L', 1),
+        ('J', 1),
+        ('7', 1),
+        ('F', 1),
+    ];
+    for position in maze.positions().collect::<Vec<(usize, usize)>>() {
+        maze[position] = weighted(rng, &junk);
+    }
+    for (index, tile) in tiles.iter().enumerate() {
+        let previous = tiles[(index + tiles.len() - 1) % tiles.len()];
+        let next = tiles[(index + 1) % tiles.len()];
+        let heading =
+            Direction::from_offset(*tile - previous).expect("the loop moves a tile at a time");
+        let exit = Direction::from_offset(next - *tile).expect("the loop moves a tile at a time");
+        maze[(tile.x as usize, tile.y as usize)] = "|-LJ7F"
+            .chars()
+            .find(|pipe| pipe_exit(*pipe, heading) == Some(exit))
+            .expect("the loop only turns by a quarter");
+    }
+    let start = tiles[rng.gen_range(0..tiles.len())];
+    maze[(start.x as usize, start.y as usize)] = 'S';
+    for neighbour in start.neighbours() {
+        if !tiles.contains(&neighbour) {
+            if let Some(tile) = maze.get_mut(neighbour.x, neighbour.y) {
+                *tile = '.';
+            }
+        }
+    }
+    format!("{}\n", maze)
+}
+
+/// Solves day 10 through the [`Solution`] trait.
+pub struct DayTen;
+
+impl Solution for DayTen {
+    type Input<'a> = Grid<char>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_maze(lines)
+    }
+
+    fn part_one(maze: &Self::Input<'_>) -> Answer {
+        part_one(maze).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part_two(maze: &Self::Input<'_>) -> Answer {
+        part_two(maze).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn render(maze: &Self::Input<'_>) -> Option<Image> {
+        Some(render(maze))
+    }
+
+    fn stepper<'b>(maze: &'b Self::Input<'_>) -> Option<Box<dyn Playback + 'b>> {
+        Some(Box::new(Stepper::new(LoopWalk { maze })))
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_ten_test.txt").unwrap();
+    let lines = input.lines();
+    assert_eq!(part_one(&get_maze(&lines).unwrap()), Some(8));
+}
+
+#[test]
+fn test_part_one_easy() {
+    let input = Input::from_data_file("data/day_ten_test_easy.txt").unwrap();
+    let lines = input.lines();
+    assert_eq!(part_one(&get_maze(&lines).unwrap()), Some(4));
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_ten_test_part_two.txt").unwrap();
+    let lines = input.lines();
+    assert_eq!(part_two(&get_maze(&lines).unwrap()), Some(4));
+    let input = Input::from_data_file("data/day_ten_test_part_two_squeezed.txt").unwrap();
+    let lines = input.lines();
+    assert_eq!(part_two(&get_maze(&lines).unwrap()), Some(4));
+    let input = Input::from_data_file("data/day_ten_test.txt").unwrap();
+    let lines = input.lines();
+    assert_eq!(part_two(&get_maze(&lines).unwrap()), Some(1));
+}
+
+#[test]
+fn test_s_coordinate() {
+    let input = Input::from_data_file("data/day_ten_test.txt").unwrap();
+    let lines = input.lines();
+    let s_coordinates = find_s_coordinates(&get_maze(&lines).unwrap());
+    assert_eq!(s_coordinates.len(), 1);
+    assert_eq!(s_coordinates[0], Point::new(0, 2));
+}
+
+#[test]
+fn test_maze_from_malformed_lines() {
+    let error = get_maze(&["garbage line"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:1: expected a pipe, `.` or `S`, found `g`"
+    );
+    let error = get_maze(&["-L|F7", "7.-7|"]).unwrap_err();
+    assert_eq!(error.to_string(), "expected an `S` where the animal starts");
+    let error = get_maze(&[".S.", ".S."]).unwrap_err();
+    assert_eq!(error.to_string(), "line 2:2: expected only one `S`");
+}
+
+#[test]
+fn test_maze_without_loop() {
+    let maze = get_maze(&["...", ".S-", "..."]).unwrap();
+    assert_eq!(part_one(&maze), None);
+    assert_eq!(DayTen::part_two(&maze), Answer::Unsolved);
+}
+
+#[test]
+fn test_pipe_exit() {
+    assert_eq!(pipe_exit('L', Direction::Down), Some(Direction::Right));
+    assert_eq!(pipe_exit('L', Direction::Left), Some(Direction::Up));
+    assert_eq!(pipe_exit('L', Direction::Up), None);
+    assert_eq!(pipe_exit('|', Direction::Up), Some(Direction::Up));
+    assert_eq!(pipe_exit('-', Direction::Up), None);
+    assert_eq!(pipe_exit('S', Direction::Right), None);
+}
+
+#[test]
+fn test_loop_walk() {
+    let maze = Input::from_data_file("data/day_ten_test.txt")
+        .unwrap()
+        .grid()
+        .unwrap();
+    let mut stepper = Stepper::new(LoopWalk { maze: &maze });
+    assert_eq!(
+        stepper.frame(),
+        "     \n     \nS@   \n     \n     \n\npath: 2 tiles"
+    );
+    let path = stepper.run(usize::MAX).clone();
+    assert!(stepper.finished());
+    assert_eq!(Some(path.len()), get_loop(&maze).map(|tiles| tiles.len()));
+    assert_eq!(path.first(), path.last());
+    assert_eq!(
+        stepper.frame(),
+        "  ┌┐ \n ┌┘│ \n@┘ └┐\n│┌──┘\n└┘   \n\npath: 17 tiles"
+    );
+}
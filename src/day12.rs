@@ -0,0 +1,269 @@
+//! Day 12: Hot Springs
+//!
+//! Count the ways the damaged `?` springs in each row can be filled in to
+//! match the sizes of its groups of broken springs.
+
+use std::borrow::Cow;
+
+use crate::advent_utils::{end_column, parse_lines, parse_number_at};
+use crate::error::AocError;
+#[cfg(test)]
+use crate::input::Input;
+use crate::solution::{Answer, Solution};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rayon::iter::IntoParallelRefIterator;
+use rayon::prelude::*;
+
+/// One row of the records, like `???.### 1,1,3`: the springs, each `.`
+/// (working), `#` (broken) or `?` (unknown), and the sizes of each run of
+/// broken springs in order.
+///
+/// The springs borrow from the input until the record is unfolded.
+#[derive(Debug, Clone)]
+pub struct ConditionRecord<'a> {
+    pub row: Cow<'a, str>,
+    pub contiguous_groups: Vec<i128>,
+}
+
+impl<'a> ConditionRecord<'a> {
+    /// Parse a row, reporting where it stopped looking like one.
+    pub fn from_row(row: &'a str) -> Result<ConditionRecord<'a>, AocError> {
+        let (springs, contiguous_string) = row.split_once(' ').ok_or_else(|| {
+            AocError::parse("expected a space between the springs and the group sizes")
+                .at_column(end_column(row))
+        })?;
+        if let Some((column, unknown)) = springs
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !matches!(c, '.' | '#' | '?'))
+        {
+            return Err(AocError::parse(format!(
+                "expected springs to be `.`, `#` or `?`, found `{}`",
+                unknown
+            ))
+            .at_column(column + 1));
+        }
+        let contiguous_groups: Vec<i128> = contiguous_string
+            .split(',')
+            .map(|group| parse_number_at::<i128>(row, group))
+            .collect::<Result<Vec<i128>, AocError>>()?;
+        Ok(ConditionRecord {
+            row: Cow::Borrowed(springs),
+            contiguous_groups,
+        })
+    }
+}
+
+impl ConditionRecord<'_> {
+    /// The row and groups repeated five times, as part two reads them.
+    pub fn unfold(&self) -> ConditionRecord<'static> {
+        ConditionRecord {
+            row: Cow::Owned(self.row.repeat(5)),
+            contiguous_groups: self.contiguous_groups.repeat(5),
+        }
+    }
+}
+
+impl ConditionRecord<'_> {
+    /// The positions of every `?` in the row.
+    pub fn get_unknown_indices(&self) -> Vec<usize> {
+        let mut unknown_indices: Vec<usize> = vec![];
+        for (i, c) in self.row.chars().enumerate() {
+            if c == '?' {
+                unknown_indices.push(i);
+            }
+        }
+        unknown_indices
+    }
+}
+
+/// The sizes of each run of `#` in `row`.
+pub fn get_contiguous_springs(row: &str) -> Vec<i128> {
+    let mut contiguous_springs: Vec<i128> = vec![];
+    let mut current_contiguous_springs = 0;
+    for c in row.chars() {
+        if c == '#' {
+            current_contiguous_springs += 1;
+        } else {
+            if current_contiguous_springs > 0 {
+                contiguous_springs.push(current_contiguous_springs);
+                current_contiguous_springs = 0;
+            }
+        }
+    }
+    if current_contiguous_springs > 0 {
+        contiguous_springs.push(current_contiguous_springs);
+    }
+    contiguous_springs
+}
+
+impl ConditionRecord<'_> {
+    /// Count the ways to fill in the `?`s that match the groups, by trying every
+    /// combination of them. A row already holding more `#`s than the groups
+    /// add up to has no arrangements.
+    pub fn get_n_arrangements(&self) -> i128 {
+        let mut arrangements = 0;
+        let n_broken_springs = self.row.chars().filter(|c| *c == '#').count();
+        let expected_broken_springs: i128 = self.contiguous_groups.iter().sum();
+        let Some(fill_n) = (expected_broken_springs as usize).checked_sub(n_broken_springs) else {
+            return 0;
+        };
+        let unknown_indices = self.get_unknown_indices();
+        let combinations = unknown_indices.iter().combinations(fill_n);
+        for combination in combinations {
+            let mut row = self.row.to_string();
+            for i in &combination {
+                row.replace_range(**i..**i + 1, "#");
+            }
+            let contiguous_springs = get_contiguous_springs(&row);
+            if contiguous_springs == self.contiguous_groups {
+                arrangements += 1;
+            }
+        }
+        arrangements
+    }
+}
+
+/// Parse every row.
+pub fn get_condition_records<'a>(lines: &[&'a str]) -> Result<Vec<ConditionRecord<'a>>, AocError> {
+    parse_lines(lines, ConditionRecord::from_row)
+}
+
+/// Sum the number of arrangements of every row.
+pub fn part_one(condition_records: &[ConditionRecord]) -> i128 {
+    let mut arrangement_sum: i128 = 0;
+    for condition_record in condition_records {
+        let arrangements = condition_record.get_n_arrangements();
+        arrangement_sum += arrangements;
+    }
+    arrangement_sum
+}
+
+/// Sum the number of arrangements of every unfolded row.
+pub fn part_two(condition_records: &[ConditionRecord]) -> i128 {
+    let sums: Vec<i128> = condition_records
+        .par_iter()
+        .map(|condition_record| condition_record.unfold().get_n_arrangements())
+        .collect();
+    let sums_sum: i128 = sums.iter().sum();
+    sums_sum
+}
+
+/// `size` rows of 1 to 20 springs with at least one broken spring each, and
+/// about two in five springs hidden behind a `?`. The group sizes are those of
+/// the springs before they were hidden, so every row has an arrangement.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size.max(1) {
+        let length = rng.gen_range(1..=20);
+        let mut springs: Vec<char> = (0..length)
+            .map(|_| if rng.gen_bool(0.4) { '#' } else { '.' })
+            .collect();
+        if !springs.contains(&'#') {
+            springs[rng.gen_range(0..length)] = '#';
+        }
+        let springs: String = springs.into_iter().collect();
+        let groups = get_contiguous_springs(&springs)
+            .iter()
+            .map(|group| group.to_string())
+            .join(",");
+        let hidden: String = springs
+            .chars()
+            .map(|spring| if rng.gen_bool(0.4) { '?' } else { spring })
+            .collect();
+        text.push_str(&format!("{} {}\n", hidden, groups));
+    }
+    text
+}
+
+/// Solves day 12 through the [`Solution`] trait.
+pub struct DayTwelve;
+
+impl Solution for DayTwelve {
+    type Input<'a> = Vec<ConditionRecord<'a>>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_condition_records(lines)
+    }
+
+    fn part_one(condition_records: &Self::Input<'_>) -> Answer {
+        part_one(condition_records).into()
+    }
+
+    fn part_two(condition_records: &Self::Input<'_>) -> Answer {
+        part_two(condition_records).into()
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_twelve_test.txt").unwrap();
+    let lines = input.lines();
+    let result = part_one(&get_condition_records(&lines).unwrap());
+    assert_eq!(result, 21);
+}
+
+#[test]
+#[ignore = "brute-forces every unfolded arrangement and does not finish"]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_twelve_test.txt").unwrap();
+    let lines = input.lines();
+    let result = part_two(&get_condition_records(&lines).unwrap());
+    assert_eq!(result, 525152);
+}
+
+#[test]
+fn test_condition_record_from_row() {
+    let row = "???.### 1,1,3";
+    let condition_record = ConditionRecord::from_row(row).unwrap();
+    assert_eq!(condition_record.row, "???.###");
+    assert_eq!(condition_record.contiguous_groups, vec![1, 1, 3]);
+}
+
+#[test]
+fn test_get_contigous_springs() {
+    let row = "???.###.###.#";
+    let contiguous_springs = get_contiguous_springs(row);
+    assert_eq!(contiguous_springs, vec![3, 3, 1]);
+}
+
+//.??..??...?##. 1,1,3
+#[test]
+fn test_get_arrangements() {
+    let row = ".??..??...?##. 1,1,3";
+    let condition_record = ConditionRecord::from_row(row).unwrap();
+    let arrangements = condition_record.get_n_arrangements();
+    assert_eq!(arrangements, 4);
+}
+
+#[test]
+fn test_get_arrangements_too_many_broken_springs() {
+    let condition_record = ConditionRecord::from_row("###.?# 1,1").unwrap();
+    assert_eq!(condition_record.get_n_arrangements(), 0);
+}
+
+#[test]
+fn test_get_unknown_indices() {
+    let row = ".??..??...?##. 1,1,3";
+    let condition_record = ConditionRecord::from_row(row).unwrap();
+    let unknown_indices = condition_record.get_unknown_indices();
+    assert_eq!(unknown_indices, vec![1, 2, 5, 6, 10]);
+}
+
+#[test]
+fn test_condition_record_from_malformed_row() {
+    let error = ConditionRecord::from_row("???.###").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected a space between the springs and the group sizes"
+    );
+    let error = ConditionRecord::from_row("???.### 1,,3").unwrap_err();
+    assert_eq!(error.to_string(), "expected a number, found ``");
+    let error = ConditionRecord::from_row("??x.### 1,1,3").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected springs to be `.`, `#` or `?`, found `x`"
+    );
+}
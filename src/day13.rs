@@ -0,0 +1,369 @@
+//! Day 13: Point of Incidence
+//!
+//! Each pattern of ash and rocks has a line of reflection between two rows
+//! or two columns; in part two exactly one smudged tile has to be flipped.
+
+use std::collections::HashSet;
+
+use log::{trace, warn};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::advent_utils::sections;
+use crate::error::AocError;
+use crate::grid::Grid;
+#[cfg(test)]
+use crate::input::Input;
+use crate::solution::{Answer, Solution};
+
+/// Split the input into patterns at the blank lines.
+pub fn get_line_blocks(lines: &[&str]) -> Result<Vec<Grid<char>>, AocError> {
+    sections(lines)
+        .into_iter()
+        .map(|(start, block)| Grid::parse(block).map_err(|error| error.after_line(start)))
+        .collect()
+}
+
+/// 100 times the number of rows above the first horizontal line of reflection,
+/// or 0 if there is none.
+pub fn check_line_block_horizontally(line_block: &Grid<char>) -> i128 {
+    let mut horizontal_sum: i128 = 0;
+    let mut potential_symmetry_points: Vec<usize> = Vec::new();
+    for i in 0..line_block.height() - 1 {
+        let line_1 = line_block.row(i);
+        let line_2 = line_block.row(i + 1);
+        if line_1 == line_2 {
+            potential_symmetry_points.push(i);
+        }
+    }
+    for potential in potential_symmetry_points.iter() {
+        let mut symmetry_point = true;
+        let range_to_check: Vec<usize> = (0..*potential).rev().collect();
+        for (i, j) in range_to_check.iter().enumerate() {
+            let left_index = j;
+            let right_index = potential + i + 2;
+            if right_index > line_block.height() - 1 || !symmetry_point {
+                break;
+            } else if line_block.row(*left_index) != line_block.row(right_index) {
+                symmetry_point = false;
+            }
+        }
+        if symmetry_point {
+            horizontal_sum += 100 * (*potential as i128 + 1);
+            return horizontal_sum;
+        }
+    }
+    horizontal_sum
+}
+
+/// 100 times the number of rows above every horizontal line of reflection.
+pub fn get_line_block_horizontally(line_block: &Grid<char>) -> Vec<i128> {
+    let mut horizontal_values: Vec<i128> = Vec::new();
+    let mut potential_symmetry_points: Vec<usize> = Vec::new();
+    for i in 0..line_block.height() - 1 {
+        let line_1 = line_block.row(i);
+        let line_2 = line_block.row(i + 1);
+        if line_1 == line_2 {
+            potential_symmetry_points.push(i);
+        }
+    }
+    for potential in potential_symmetry_points.iter() {
+        let mut symmetry_point = true;
+        let range_to_check: Vec<usize> = (0..*potential).rev().collect();
+        for (i, j) in range_to_check.iter().enumerate() {
+            let left_index = j;
+            let right_index = potential + i + 2;
+            if right_index > line_block.height() - 1 || !symmetry_point {
+                break;
+            } else if line_block.row(*left_index) != line_block.row(right_index) {
+                symmetry_point = false;
+            }
+        }
+        if symmetry_point {
+            horizontal_values.push(100 * (*potential as i128 + 1));
+        }
+    }
+    horizontal_values
+}
+
+/// The number of columns left of the first vertical line of reflection, or 0
+/// if there is none.
+pub fn check_line_block_vertically(line_block: &Grid<char>) -> i128 {
+    let mut vertical_sum: i128 = 0;
+    let mut potential_symmetry_points: Vec<usize> = Vec::new();
+    for i in 0..line_block.width() - 1 {
+        if line_block.column(i).eq(line_block.column(i + 1)) {
+            potential_symmetry_points.push(i);
+        }
+    }
+    for potential_symmetry_point in potential_symmetry_points.iter() {
+        let mut symmetry_point = true;
+        let range_to_check: Vec<usize> = (0..*potential_symmetry_point).rev().collect();
+        for (i, j) in range_to_check.iter().enumerate() {
+            let left_index = j;
+            let right_index = potential_symmetry_point + i + 2;
+            if right_index > line_block.width() - 1 || !symmetry_point {
+                break;
+            } else if line_block
+                .column(*left_index)
+                .ne(line_block.column(right_index))
+            {
+                symmetry_point = false;
+            }
+        }
+        if symmetry_point {
+            vertical_sum += *potential_symmetry_point as i128 + 1;
+        }
+    }
+    vertical_sum
+}
+
+/// The number of columns left of every vertical line of reflection.
+pub fn get_check_line_block_vertically(line_block: &Grid<char>) -> Vec<i128> {
+    let mut vertical_sums: Vec<i128> = Vec::new();
+    let mut potential_symmetry_points: Vec<usize> = Vec::new();
+    for i in 0..line_block.width() - 1 {
+        if line_block.column(i).eq(line_block.column(i + 1)) {
+            potential_symmetry_points.push(i);
+        }
+    }
+    for potential_symmetry_point in potential_symmetry_points.iter() {
+        let mut symmetry_point = true;
+        let range_to_check: Vec<usize> = (0..*potential_symmetry_point).rev().collect();
+        for (i, j) in range_to_check.iter().enumerate() {
+            let left_index = j;
+            let right_index = potential_symmetry_point + i + 2;
+            if right_index > line_block.width() - 1 || !symmetry_point {
+                break;
+            } else if line_block
+                .column(*left_index)
+                .ne(line_block.column(right_index))
+            {
+                symmetry_point = false;
+            }
+        }
+        if symmetry_point {
+            vertical_sums.push(*potential_symmetry_point as i128 + 1);
+        }
+    }
+    vertical_sums
+}
+
+/// Summarise the line of reflection of every pattern.
+pub fn part_one(line_blocks: &[Grid<char>]) -> i128 {
+    let mut answer: i128 = 0;
+    for line_block in line_blocks.iter() {
+        let horizontal_answer = check_line_block_horizontally(line_block);
+        let vertical_answer = check_line_block_vertically(line_block);
+        if horizontal_answer != 0 {
+            answer += horizontal_answer;
+        } else if vertical_answer != 0 {
+            answer += vertical_answer;
+        } else {
+            // println!("{}", line_block.join("\n"));
+        }
+    }
+    answer
+}
+
+/// Summarise the new line of reflection of every pattern once its smudge is fixed.
+pub fn part_two(line_blocks: &[Grid<char>]) -> i128 {
+    let mut answer: i128 = 0;
+    for line_block in line_blocks.iter() {
+        let original_horizontal_answer = check_line_block_horizontally(line_block);
+        let original_vertical_answer = check_line_block_vertically(line_block);
+        trace!(
+            "original horizontal reflection: {}",
+            original_horizontal_answer
+        );
+        let mut match_found = false;
+        for y in 0..line_block.height() {
+            for x in 0..line_block.width() {
+                let mut smudge_fixed_line_block = line_block.clone();
+                let smudge = &mut smudge_fixed_line_block[(x, y)];
+                if *smudge == '#' {
+                    *smudge = '.';
+                } else {
+                    *smudge = '#';
+                }
+                let horizontal_answers = get_line_block_horizontally(&smudge_fixed_line_block);
+                for horizontal_answer in horizontal_answers.iter() {
+                    if *horizontal_answer != original_horizontal_answer {
+                        // println!("{}", smudge_fixed_line_block.join("\n"));
+                        trace!("horizontal reflection once fixed: {}", horizontal_answer);
+                        answer += horizontal_answer;
+                        match_found = true;
+                        break;
+                    }
+                }
+                let vertical_answers = get_check_line_block_vertically(&smudge_fixed_line_block);
+                for vertical_answer in vertical_answers.iter() {
+                    if *vertical_answer != original_vertical_answer {
+                        // println!("{}", smudge_fixed_line_block.join("\n"));
+                        trace!("vertical reflection once fixed: {}", vertical_answer);
+                        answer += vertical_answer;
+                        match_found = true;
+                        break;
+                    }
+                }
+                if match_found {
+                    break;
+                }
+            }
+            if match_found {
+                break;
+            }
+        }
+        if !match_found {
+            warn!("no smudge changes the reflection of\n{}", line_block);
+        }
+    }
+    answer
+}
+
+/// The summary of every line of reflection in `pattern`, found directly so
+/// that [`generate_input`] does not share any mistakes of the functions above.
+fn reflection_summaries(pattern: &Grid<char>) -> Vec<i128> {
+    let lines = |pattern: &Grid<char>| -> Vec<usize> {
+        (1..pattern.height())
+            .filter(|&line| {
+                (0..line.min(pattern.height() - line))
+                    .all(|offset| pattern.row(line - 1 - offset) == pattern.row(line + offset))
+            })
+            .collect()
+    };
+    let rows = lines(pattern).into_iter().map(|line| 100 * line as i128);
+    let columns = lines(&pattern.transpose())
+        .into_iter()
+        .map(|line| line as i128);
+    rows.chain(columns).collect()
+}
+
+/// A pattern of 5 to 17 rows and columns with exactly one line of reflection
+/// and exactly one other line that fixing a smudge would reveal.
+fn generate_pattern(rng: &mut StdRng) -> Grid<char> {
+    loop {
+        let height = rng.gen_range(5..=17);
+        let width = rng.gen_range(5..=17);
+        // the smudge must be on a row that the row reflection leaves out
+        let row_line = rng.gen_range(1..height);
+        if 2 * row_line == height {
+            continue;
+        }
+        let column_line = rng.gen_range(1..width);
+        let row_reach = row_line.min(height - row_line);
+        let column_reach = column_line.min(width - column_line);
+        let mut pattern = Grid::new(width, height, '.');
+        for y in 0..height {
+            for x in 0..width {
+                if rng.gen_bool(0.5) {
+                    pattern[(x, y)] = '#';
+                }
+            }
+            for offset in 0..column_reach {
+                pattern[(column_line + offset, y)] = pattern[(column_line - 1 - offset, y)];
+            }
+        }
+        for offset in 0..row_reach {
+            for x in 0..width {
+                pattern[(x, row_line + offset)] = pattern[(x, row_line - 1 - offset)];
+            }
+        }
+        let smudge_y = if row_line + row_reach < height {
+            rng.gen_range(row_line + row_reach..height)
+        } else {
+            rng.gen_range(0..row_line - row_reach)
+        };
+        let smudge_x = rng.gen_range(column_line - column_reach..column_line + column_reach);
+        let smudge = &mut pattern[(smudge_x, smudge_y)];
+        *smudge = if *smudge == '#' { '.' } else { '#' };
+        if rng.gen_bool(0.5) {
+            pattern = pattern.transpose();
+        }
+        let original = reflection_summaries(&pattern);
+        let mut fixed: HashSet<i128> = HashSet::new();
+        for position in pattern.positions() {
+            let mut smudge_fixed = pattern.clone();
+            let cell = &mut smudge_fixed[position];
+            *cell = if *cell == '#' { '.' } else { '#' };
+            fixed.extend(
+                reflection_summaries(&smudge_fixed)
+                    .into_iter()
+                    .filter(|summary| !original.contains(summary)),
+            );
+        }
+        if original.len() == 1 && fixed.len() == 1 {
+            return pattern;
+        }
+    }
+}
+
+/// `size` patterns of 5 to 17 rows and columns. Each has exactly one line of
+/// reflection, and one smudge that, once fixed, gives exactly one new line.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size.max(1))
+        .map(|_| generate_pattern(rng).to_string())
+        .collect();
+    format!("{}\n", patterns.join("\n\n"))
+}
+
+/// Solves day 13 through the [`Solution`] trait.
+pub struct DayThirteen;
+
+impl Solution for DayThirteen {
+    type Input<'a> = Vec<Grid<char>>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_line_blocks(lines)
+    }
+
+    fn part_one(line_blocks: &Self::Input<'_>) -> Answer {
+        part_one(line_blocks).into()
+    }
+
+    fn part_two(line_blocks: &Self::Input<'_>) -> Answer {
+        part_two(line_blocks).into()
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_thirteen_test.txt").unwrap();
+    let lines = input.lines();
+    let result = part_one(&get_line_blocks(&lines).unwrap());
+    assert_eq!(result, 405);
+}
+
+#[test]
+#[ignore = "fixture also reflects vertically after column 16"]
+fn test_part_one_extra() {
+    let input = Input::from_data_file("data/day_thirteen_test_2.txt").unwrap();
+    let lines = input.lines();
+    let result = part_one(&get_line_blocks(&lines).unwrap());
+    assert_eq!(result, 500);
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_thirteen_test.txt").unwrap();
+    let lines = input.lines();
+    let result = part_two(&get_line_blocks(&lines).unwrap());
+    assert_eq!(result, 400);
+}
+
+#[test]
+fn test_ragged_pattern() {
+    let lines = ["#.#", "", "#.", "##", "#.#"];
+    let error = get_line_blocks(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 5:3: expected a row of 2 cells, found 3"
+    );
+}
+
+#[test]
+fn test_trailing_blank_lines() {
+    let input = Input::new("#.\r\n##\r\n\r\n\r\n");
+    let line_blocks = get_line_blocks(&input.lines()).unwrap();
+    assert_eq!(line_blocks.len(), 1);
+}
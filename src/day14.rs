@@ -0,0 +1,295 @@
+//! Day 14: Parabolic Reflector Dish
+//!
+//! Tilt the platform so the rounded rocks `O` roll until they hit a
+//! cube-shaped rock `#` or the edge, then weigh the load on the north beams.
+
+use log::{debug, trace};
+use rand::rngs::StdRng;
+
+use crate::cycle::find_cycle_hashed;
+use crate::error::AocError;
+use crate::generate::weighted;
+use crate::grid::Grid;
+#[cfg(test)]
+use crate::input::Input;
+use crate::solution::{Answer, Solution};
+use crate::stepper::{Playback, Simulation, Stepper};
+
+/// The total load on the north support beams.
+pub fn score_dish(dish: &Grid<char>) -> i128 {
+    let mut score: i128 = 0;
+    for (i, line) in dish.rows().enumerate() {
+        for char in line.iter() {
+            if *char == 'O' {
+                score += dish.height() as i128 - i as i128;
+            }
+        }
+    }
+    score
+}
+
+/// Apply `tilt` to every row of the dish, rolling the rocks towards its left edge.
+fn tilt_rows(dish: &Grid<char>, tilt: impl Fn(&str) -> String) -> Grid<char> {
+    let rows = dish
+        .rows()
+        .map(|row| tilt(&row.iter().collect::<String>()).chars().collect())
+        .collect();
+    Grid::from_rows(rows).expect("tilting keeps every row the same length")
+}
+
+/// Roll every `O` in `line` as far towards the start as it can go.
+pub fn tilt_line(line: &str) -> String {
+    let mut new_line = String::new();
+    let rounded_rock_locations = line
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| *c == 'O')
+        .map(|(i, _)| i)
+        .collect::<Vec<usize>>();
+    let cube_shaped_rock_locations = line
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| *c == '#')
+        .map(|(i, _)| i)
+        .collect::<Vec<usize>>();
+    let mut new_rock_position = 0;
+    let mut new_rock_positions: Vec<usize> = Vec::new();
+    let mut last_rock_position = 1;
+    if !cube_shaped_rock_locations.is_empty() {
+        last_rock_position = cube_shaped_rock_locations[cube_shaped_rock_locations.len() - 1] + 1;
+    }
+    for rounded_rock in rounded_rock_locations.iter() {
+        for (cube_i, cube_shaped_rock) in cube_shaped_rock_locations.clone().iter().enumerate() {
+            if rounded_rock < cube_shaped_rock {
+                new_rock_positions.push(new_rock_position);
+                new_rock_position += 1;
+                break;
+            } else if cube_i == cube_shaped_rock_locations.len() - 1 {
+                new_rock_positions.push(last_rock_position);
+                last_rock_position += 1;
+            } else if new_rock_position <= cube_shaped_rock + 1 {
+                new_rock_position = cube_shaped_rock + 1;
+            }
+        }
+        if cube_shaped_rock_locations.is_empty() {
+            new_rock_positions.push(new_rock_position);
+            new_rock_position += 1;
+        }
+    }
+    for (i, c) in line.chars().enumerate() {
+        if new_rock_positions.contains(&i) {
+            new_line.push('O');
+        } else if c == 'O' {
+            new_line.push('.')
+        } else {
+            new_line.push(c);
+        }
+    }
+    new_line
+}
+
+/// Roll every `O` in `line` as far towards the start as it can go, one step at
+/// a time. Slower than [`tilt_line`] but simple enough to check it against.
+pub fn tilt_line_simpler(line: &str) -> String {
+    let mut new_line = line.to_string();
+    for _ in 0..line.len() {
+        for c in 0..line.len() {
+            if new_line.chars().nth(c).unwrap() == 'O'
+                && c > 0
+                && new_line.chars().nth(c - 1).unwrap() == '.'
+            {
+                new_line.replace_range(c - 1..c + 1, "O.");
+            }
+        }
+    }
+    new_line
+}
+
+/// The load after tilting the platform north.
+pub fn part_one(dish: &Grid<char>) -> i128 {
+    let initial_count = dish.iter().filter(|(_, c)| **c == 'O').count();
+    let untilted_dish = tilt_rows(&dish.transpose(), tilt_line).transpose();
+    let final_count = untilted_dish.iter().filter(|(_, c)| **c == 'O').count();
+    trace!("tilted north:\n{}", untilted_dish);
+    debug!(
+        "rounded rocks before and after tilting: {} {}",
+        initial_count, final_count
+    );
+    score_dish(&untilted_dish)
+}
+
+/// One spin cycle: tilt north, then west, then south, then east.
+pub fn cycle_dish(dish: &Grid<char>) -> Grid<char> {
+    // north
+    let north_tilted_dish = tilt_rows(&dish.transpose(), tilt_line).transpose();
+    // west
+    let west_tilted_dish = tilt_rows(&north_tilted_dish, tilt_line);
+    // south
+    let south_tilted_dish = tilt_rows(&west_tilted_dish.flip_vertical().transpose(), tilt_line)
+        .transpose()
+        .flip_vertical();
+    // east
+    tilt_rows(&south_tilted_dish.flip_horizontal(), tilt_line).flip_horizontal()
+}
+
+/// The load after 1,000,000,000 spin cycles.
+///
+/// The rocks settle into a loop of arrangements long before that, so only
+/// the spins up to the first repeat are simulated.
+pub fn part_two(dish: &Grid<char>) -> i128 {
+    let history = find_cycle_hashed(dish.clone(), cycle_dish);
+    score_dish(history.state_at(1_000_000_000))
+}
+
+/// The platform going through one spin cycle after another, for the stepper.
+pub struct SpinCycles<'a> {
+    pub dish: &'a Grid<char>,
+}
+
+impl Simulation for SpinCycles<'_> {
+    type State = Grid<char>;
+
+    fn initial(&self) -> Grid<char> {
+        self.dish.clone()
+    }
+
+    fn step(&self, dish: &Grid<char>) -> Option<Grid<char>> {
+        Some(cycle_dish(dish))
+    }
+
+    fn draw(&self, dish: &Grid<char>) -> String {
+        format!("{}\n\nload: {}", dish, score_dish(dish))
+    }
+}
+
+/// A `size` by `size` platform where about one tile in six is a rounded rock
+/// and one in six a cube-shaped rock.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let side = size.max(1);
+    let mut dish = Grid::new(side, side, '.');
+    for position in dish.positions().collect::<Vec<(usize, usize)>>() {
+        dish[position] = weighted(rng, &[('.', 4), ('O', 1), ('#', 1)]);
+    }
+    format!("{}\n", dish)
+}
+
+/// Solves day 14 through the [`Solution`] trait.
+pub struct DayFourteen;
+
+impl Solution for DayFourteen {
+    type Input<'a> = Grid<char>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        Grid::parse(lines)
+    }
+
+    fn part_one(dish: &Self::Input<'_>) -> Answer {
+        part_one(dish).into()
+    }
+
+    fn part_two(dish: &Self::Input<'_>) -> Answer {
+        part_two(dish).into()
+    }
+
+    fn stepper<'b>(dish: &'b Self::Input<'_>) -> Option<Box<dyn Playback + 'b>> {
+        Some(Box::new(Stepper::new(SpinCycles { dish })))
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let dish = Input::from_data_file("data/day_14_test.txt")
+        .unwrap()
+        .grid()
+        .unwrap();
+    assert_eq!(part_one(&dish), 136)
+}
+
+#[test]
+fn test_part_two() {
+    let dish = Input::from_data_file("data/day_14_test.txt")
+        .unwrap()
+        .grid()
+        .unwrap();
+    assert_eq!(part_two(&dish), 64)
+}
+
+#[test]
+fn test_dish_score() {
+    let dish = Input::from_data_file("data/day_14_test_score.txt")
+        .unwrap()
+        .grid()
+        .unwrap();
+    assert_eq!(score_dish(&dish), 136)
+}
+
+#[test]
+fn test_tilt_line() {
+    let line = "...OOO".to_string();
+    assert_eq!(tilt_line(&line), "OOO...");
+    let line = "#..OOO".to_string();
+    assert_eq!(tilt_line(&line), "#OOO..");
+    let line = "#.O#..O".to_string();
+    assert_eq!(tilt_line(&line), "#O.#O..");
+    let line = "#.O#.OO".to_string();
+    assert_eq!(tilt_line(&line), "#O.#OO.");
+    let line = "#OO#.OO".to_string();
+    assert_eq!(tilt_line(&line), "#OO#OO.");
+}
+
+#[test]
+fn test_tilt_line_simpler() {
+    let line = "...OOO".to_string();
+    assert_eq!(tilt_line_simpler(&line), "OOO...");
+    let line = "#..OOO".to_string();
+    assert_eq!(tilt_line_simpler(&line), "#OOO..");
+    let line = "#.O#..O".to_string();
+    assert_eq!(tilt_line_simpler(&line), "#O.#O..");
+    let line = "#.O#.OO".to_string();
+    assert_eq!(tilt_line_simpler(&line), "#O.#OO.");
+    let line = "#OO#.OO".to_string();
+    assert_eq!(tilt_line_simpler(&line), "#OO#OO.");
+}
+
+#[test]
+fn test_two_cycles() {
+    let dish = Input::from_data_file("data/day_14_test.txt")
+        .unwrap()
+        .grid()
+        .unwrap();
+    let mut cycled_dish = cycle_dish(&dish);
+    cycled_dish = cycle_dish(&cycled_dish);
+    let answer_input = Input::from_data_file("data/day_14_test_two_cycles.txt").unwrap();
+    let answer = answer_input.lines();
+    assert_eq!(cycled_dish, Grid::parse(&answer).unwrap());
+}
+
+#[test]
+fn test_three_cycles() {
+    let dish = Input::from_data_file("data/day_14_test.txt")
+        .unwrap()
+        .grid()
+        .unwrap();
+    let mut cycled_dish = cycle_dish(&dish);
+    cycled_dish = cycle_dish(&cycled_dish);
+    cycled_dish = cycle_dish(&cycled_dish);
+    let answer_input = Input::from_data_file("data/day_14_test_three_cycles.txt").unwrap();
+    let answer = answer_input.lines();
+    assert_eq!(cycled_dish, Grid::parse(&answer).unwrap());
+}
+
+#[test]
+fn test_spin_cycles() {
+    let dish = Input::from_data_file("data/day_14_test.txt")
+        .unwrap()
+        .grid()
+        .unwrap();
+    let mut stepper = Stepper::new(SpinCycles { dish: &dish });
+    let twice = cycle_dish(&cycle_dish(&dish));
+    assert_eq!(*stepper.run(3), cycle_dish(&twice));
+    assert!(stepper.back());
+    assert_eq!(
+        stepper.frame(),
+        format!("{}\n\nload: {}", twice, score_dish(&twice))
+    );
+}
@@ -0,0 +1,348 @@
+//! Day 15: Lens Library
+//!
+//! The HASH algorithm turns each step of the initialization sequence into a
+//! box number; the steps then add, replace and remove lenses in the boxes.
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::advent_utils::column_of;
+use crate::error::AocError;
+#[cfg(test)]
+use crate::input::Input;
+use crate::solution::{Answer, Solution};
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// One step such as `rn=1` or `cm-`.
+#[derive(Debug, Clone)]
+pub struct SequenceStep {
+    /// The box number, the HASH of the label.
+    pub id: u128,
+    pub label: String,
+    /// `=` to put a lens in the box or `-` to take it out.
+    pub modifier: char,
+    /// The whole step as written.
+    pub full: String,
+}
+
+impl SequenceStep {
+    /// The focal length after `=`.
+    pub fn get_focal_length(&self) -> u128 {
+        self.full.chars().next_back().unwrap().to_digit(10).unwrap() as u128
+    }
+}
+
+impl SequenceStep {
+    /// Parse a step, checking it has an operator and, after `=`, a single digit focal length.
+    pub fn from_string(s: &str) -> Result<SequenceStep, AocError> {
+        let id = hash_string_ignoring_operator(s);
+
+        let mut label_chars: Vec<char> = Vec::new();
+        for c in s.chars() {
+            if c == '=' || c == '-' {
+                break;
+            }
+            label_chars.push(c);
+        }
+        let value = label_chars.into_iter().collect();
+        let modifier = s
+            .chars()
+            .find(|c| c == &'=' || c == &'-')
+            .ok_or_else(|| AocError::parse(format!("expected `=` or `-` in step `{}`", s)))?;
+        let focal_length = &s[s.find(modifier).unwrap_or(0) + 1..];
+        if modifier == '='
+            && !(focal_length.len() == 1 && focal_length.chars().all(|c| c.is_ascii_digit()))
+        {
+            return Err(AocError::parse(format!(
+                "expected a single digit focal length after `=` in step `{}`",
+                s
+            )));
+        }
+        if modifier == '-' && !focal_length.is_empty() {
+            return Err(AocError::parse(format!(
+                "expected nothing after `-` in step `{}`",
+                s
+            )));
+        }
+        Ok(SequenceStep {
+            id,
+            label: value,
+            modifier,
+            full: s.to_string(),
+        })
+    }
+}
+
+/// The lenses in one box, front to back.
+#[derive(Debug, Clone)]
+pub struct Box {
+    pub labels: Vec<SequenceStep>,
+}
+
+impl Box {
+    /// A box holding just the lens from `label`.
+    pub fn from_label(label: SequenceStep) -> Box {
+        Box {
+            labels: vec![label],
+        }
+    }
+}
+
+impl Box {
+    /// Apply a step: `=` replaces the lens with the same label or adds it at the
+    /// back, `-` removes it.
+    pub fn update_labels(&mut self, label: &SequenceStep) {
+        if label.modifier == '=' {
+            // check if there is a label that has the same id
+            let mut found = false;
+            let mut new_labels: Vec<SequenceStep> = vec![];
+            for l in self.labels.iter_mut() {
+                if l.label == label.label {
+                    new_labels.push(label.clone());
+                    found = true;
+                } else {
+                    new_labels.push(l.clone());
+                }
+            }
+            if !found {
+                new_labels.push(label.clone());
+            }
+            self.labels = new_labels;
+        } else {
+            // check if there is a label that has the same id and remove it
+            let mut new_labels: Vec<SequenceStep> = vec![];
+            for l in self.labels.iter() {
+                if l.label != label.label {
+                    new_labels.push(l.clone());
+                }
+            }
+            self.labels = new_labels;
+        }
+    }
+}
+
+/// Parse the comma separated steps on every line.
+pub fn get_sequence_steps(lines: &[&str]) -> Result<Vec<SequenceStep>, AocError> {
+    let mut steps: Vec<SequenceStep> = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        for s in line.split(',') {
+            steps.push(
+                SequenceStep::from_string(s).map_err(|error| {
+                    error.at_column(column_of(line, s)).at_line(index + 1, line)
+                })?,
+            );
+        }
+    }
+    Ok(steps)
+}
+
+/// Sum the HASH of every step.
+pub fn part_one(steps: &[SequenceStep]) -> u128 {
+    let mut hash_sum = 0;
+    for step in steps.iter() {
+        hash_sum += hash_string(&step.full);
+    }
+
+    hash_sum
+}
+
+/// The HASH algorithm: a value in `0..256` for any string.
+pub fn hash_string(s: &str) -> u128 {
+    let mut hash = 0;
+    for c in s.chars() {
+        hash += c as u128;
+        hash *= 17;
+        hash %= 256;
+    }
+    hash
+}
+
+/// The HASH of a step's label, ignoring everything from its operator on.
+pub fn hash_string_ignoring_operator(s: &str) -> u128 {
+    let mut hash = 0;
+    for c in s.chars() {
+        if c == '=' || c == '-' {
+            break;
+        }
+        hash += c as u128;
+        hash *= 17;
+        hash %= 256;
+    }
+    hash
+}
+
+/// The focusing power of the lenses once every step has been applied.
+pub fn part_two(steps: &[SequenceStep]) -> u128 {
+    let mut box_hashmap: HashMap<u128, Box> = HashMap::new();
+    for step in steps.iter() {
+        if let Some(x) = box_hashmap.get_mut(&step.id) {
+            x.update_labels(step)
+        } else if step.modifier == '=' {
+            box_hashmap.insert(step.id, Box::from_label(step.clone()));
+        }
+    }
+    let mut sum = 0;
+    for (key, box_value) in box_hashmap.iter() {
+        for (i, label) in box_value.labels.iter().enumerate() {
+            sum += (key + 1) * (i as u128 + 1) * label.get_focal_length();
+        }
+    }
+    sum
+}
+
+/// A sequence of `size` steps on one line. The labels are one to six letters
+/// long and shared between about four steps each, so lenses get replaced and
+/// removed as well as added.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let labels: Vec<String> = (0..size.div_ceil(4))
+        .map(|_| {
+            let length = rng.gen_range(1..=6);
+            (0..length).map(|_| rng.gen_range('a'..='z')).collect()
+        })
+        .collect();
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = labels.choose(rng).expect("there is at least one label");
+            if rng.gen_bool(0.6) {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            } else {
+                format!("{}-", label)
+            }
+        })
+        .collect();
+    format!("{}\n", steps.join(","))
+}
+
+/// Solves day 15 through the [`Solution`] trait.
+pub struct DayFifteen;
+
+impl Solution for DayFifteen {
+    type Input<'a> = Vec<SequenceStep>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_sequence_steps(lines)
+    }
+
+    fn part_one(steps: &Self::Input<'_>) -> Answer {
+        part_one(steps).into()
+    }
+
+    fn part_two(steps: &Self::Input<'_>) -> Answer {
+        part_two(steps).into()
+    }
+}
+
+#[test]
+fn test_hash_string() {
+    assert_eq!(hash_string("rn=1"), 30);
+    assert_eq!(hash_string_ignoring_operator("rn=1"), 0)
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_15_test.txt").unwrap();
+    let lines = input.lines();
+    assert_eq!(part_one(&get_sequence_steps(&lines).unwrap()), 1320);
+}
+
+#[test]
+fn test_part_one_windows_line_endings() {
+    let text = std::fs::read_to_string("data/day_15_test.txt").unwrap();
+    let input = Input::new(&format!("\u{feff}{}\r\n\r\n", text.trim_end()));
+    let lines = input.lines();
+    assert_eq!(part_one(&get_sequence_steps(&lines).unwrap()), 1320);
+}
+
+#[test]
+fn test_get_focal_length() {
+    let s = SequenceStep::from_string("rn=1").unwrap();
+    assert_eq!(s.get_focal_length(), 1);
+}
+
+#[test]
+fn test_update_box() {
+    let s = SequenceStep::from_string("rn=1").unwrap();
+    let mut b = Box::from_label(s);
+    let z = SequenceStep::from_string("rn=0").unwrap();
+    b.update_labels(&z);
+    for l in b.labels.iter() {
+        assert_eq!(l.get_focal_length(), 0);
+    }
+}
+
+#[test]
+fn test_update_box_removing() {
+    let s = SequenceStep::from_string("rn=1").unwrap();
+    let mut b = Box::from_label(s);
+    let z = SequenceStep::from_string("rn-").unwrap();
+    b.update_labels(&z);
+    assert_eq!(b.labels.len(), 0);
+}
+
+#[test]
+fn test_update_box_no_value() {
+    let s = SequenceStep::from_string("rn=1").unwrap();
+    let mut b = Box::from_label(s);
+    let z = SequenceStep::from_string("cm-").unwrap();
+    b.update_labels(&z);
+    assert_eq!(b.labels.len(), 1);
+}
+
+#[test]
+fn test_update_box_adding() {
+    let s = SequenceStep::from_string("rn=1").unwrap();
+    let mut b = Box::from_label(s);
+    let z = SequenceStep::from_string("by=4").unwrap();
+    b.update_labels(&z);
+    assert_eq!(b.labels.len(), 2);
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_15_test.txt").unwrap();
+    let lines = input.lines();
+    assert_eq!(part_two(&get_sequence_steps(&lines).unwrap()), 145);
+}
+
+#[test]
+fn test_sequence_step_from_malformed_string() {
+    let error = SequenceStep::from_string("rn1").unwrap_err();
+    assert_eq!(error.to_string(), "expected `=` or `-` in step `rn1`");
+    let error = SequenceStep::from_string("rn=").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected a single digit focal length after `=` in step `rn=`"
+    );
+    let lines: Vec<&str> = vec!["rn=1,cm-", "qp=3,cm-2"];
+    let error = get_sequence_steps(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2:6: expected nothing after `-` in step `cm-2`"
+    );
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn test_hash_string_is_a_box_number(s in any::<String>()) {
+        prop_assert!(hash_string(&s) < 256);
+        prop_assert!(hash_string_ignoring_operator(&s) < 256);
+    }
+
+    #[test]
+    fn test_hash_string_matches_a_byte_fold(s in "[ -~]*") {
+        // the puzzle's arithmetic modulo 256 is what a byte does when it wraps
+        let reference = |s: &str| {
+            s.bytes()
+                .fold(0u8, |hash, byte| hash.wrapping_add(byte).wrapping_mul(17))
+        };
+        prop_assert_eq!(hash_string(&s), u128::from(reference(&s)));
+        let label = s.split(['=', '-']).next().unwrap_or_default();
+        prop_assert_eq!(hash_string_ignoring_operator(&s), u128::from(reference(label)));
+    }
+}
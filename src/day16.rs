@@ -0,0 +1,369 @@
+//! Day 16: The Floor Will Be Lava
+//!
+//! A beam of light bounces off mirrors and splits at splitters; count the
+//! tiles it energizes. Positions are [`Point`]s with `y` counting rows down.
+
+use std::collections::HashSet;
+
+use log::{trace, warn};
+use rand::rngs::StdRng;
+
+use crate::error::AocError;
+use crate::generate::weighted;
+use crate::graph::bfs;
+use crate::grid::Grid;
+#[cfg(test)]
+use crate::input::Input;
+use crate::point::{Direction, Point};
+use crate::render::{Image, Palette, BLACK, BLUE, WHITE, YELLOW};
+use crate::solution::{Answer, Solution};
+use crate::stepper::{Playback, Simulation, Stepper};
+
+/// A beam's previous position and its current position, which also gives its direction.
+pub type BeamStep = (Point, Point);
+
+/// Where a beam heading in `direction` goes after reaching `tile`; splitters
+/// send it two ways.
+pub fn beam_directions(tile: char, direction: Direction) -> Vec<Direction> {
+    match tile {
+        '.' => vec![direction],
+        '-' if direction.is_horizontal() => vec![direction],
+        '|' if !direction.is_horizontal() => vec![direction],
+        '-' => vec![Direction::Right, Direction::Left],
+        '|' => vec![Direction::Down, Direction::Up],
+        '/' if direction.is_horizontal() => vec![direction.turn_left()],
+        '/' => vec![direction.turn_right()],
+        '\\' if direction.is_horizontal() => vec![direction.turn_right()],
+        '\\' => vec![direction.turn_left()],
+        _ => {
+            warn!("unexpected character: {}", tile);
+            Vec::new()
+        }
+    }
+}
+
+/// Whether `step` is a single step that ends inside the contraption.
+fn in_contraption(
+    contraption: &Grid<char>,
+    (previous_position, current_position): BeamStep,
+) -> bool {
+    Direction::from_offset(current_position - previous_position).is_some()
+        && contraption.contains(current_position.x, current_position.y)
+}
+
+/// The steps a beam takes straight after `step`, which may leave the contraption.
+pub fn next_beam_steps(contraption: &Grid<char>, step: BeamStep) -> Vec<BeamStep> {
+    let (previous_position, current_position) = step;
+    let Some(direction) = Direction::from_offset(current_position - previous_position) else {
+        return Vec::new();
+    };
+    let Some(&current_position_char) = contraption.get(current_position.x, current_position.y)
+    else {
+        return Vec::new();
+    };
+    beam_directions(current_position_char, direction)
+        .into_iter()
+        .map(|new_direction| (current_position, current_position.step(new_direction)))
+        .collect()
+}
+
+/// Follow a beam that has moved from `previous_position` to `current_position`,
+/// recording every step it takes in `sampled_positions` until it leaves the
+/// contraption or repeats a step already recorded.
+pub fn move_beam(
+    contraption: &Grid<char>,
+    current_position: Point,
+    previous_position: Point,
+    sampled_positions: &mut HashSet<BeamStep>,
+) {
+    let start: BeamStep = (previous_position, current_position);
+    if !in_contraption(contraption, start) || sampled_positions.contains(&start) {
+        return;
+    }
+    let search = bfs([start], |step| {
+        next_beam_steps(contraption, *step)
+            .into_iter()
+            .filter(|next| in_contraption(contraption, *next) && !sampled_positions.contains(next))
+            .collect::<Vec<BeamStep>>()
+    });
+    sampled_positions.extend(search.reached().copied());
+}
+
+/// Every tile of the contraption a beam energizes after moving from
+/// `previous_position`, which may be just outside it, to `current_position`.
+pub fn energized_tiles(
+    contraption: &Grid<char>,
+    previous_position: Point,
+    current_position: Point,
+) -> HashSet<Point> {
+    let mut sampled_positions: HashSet<BeamStep> = HashSet::new();
+    move_beam(
+        contraption,
+        current_position,
+        previous_position,
+        &mut sampled_positions,
+    );
+    let mut unique_positions: HashSet<Point> = HashSet::new();
+    for (pos1, pos2) in sampled_positions {
+        for position in [pos1, pos2] {
+            if contraption.contains(position.x, position.y) {
+                unique_positions.insert(position);
+            }
+        }
+    }
+    unique_positions
+}
+
+/// The beam of part one, entering the top left tile from its left.
+pub const TOP_LEFT_START: BeamStep = (Point::new(-1, 0), Point::new(0, 0));
+
+/// The number of tiles energized by a beam entering the top left heading right.
+pub fn part_one(contraption: &Grid<char>) -> i128 {
+    let (previous_position, current_position) = TOP_LEFT_START;
+    energized_tiles(contraption, previous_position, current_position).len() as i128
+}
+
+/// Every way a beam can enter the contraption from an edge, as a step from
+/// just outside onto the edge tile, so that the edge tile turns or splits it.
+pub fn get_starting_positions(contraption: &Grid<char>) -> Vec<BeamStep> {
+    let mut starting_positions: Vec<BeamStep> = Vec::new();
+    let max_x = contraption.width() as i64 - 1;
+    let max_y = contraption.height() as i64 - 1;
+    for x in 0..max_x + 1 {
+        let top = Point::new(x, 0);
+        let bottom = Point::new(x, max_y);
+        starting_positions.push((top.step(Direction::Up), top));
+        starting_positions.push((bottom.step(Direction::Down), bottom));
+    }
+    for y in 0..max_y + 1 {
+        let left = Point::new(0, y);
+        let right = Point::new(max_x, y);
+        starting_positions.push((left.step(Direction::Left), left));
+        starting_positions.push((right.step(Direction::Right), right));
+    }
+    starting_positions
+}
+
+/// The most tiles any beam entering from an edge can energize.
+pub fn part_two(contraption: &Grid<char>) -> i128 {
+    let starting_positions: Vec<BeamStep> = get_starting_positions(contraption);
+    let mut max_unique_positions: i128 = 0;
+    // let unique_positions_list: Vec<i128> = starting_positions
+    //     .par_iter()
+    //     .map(|x| {
+    //         let mut sampled_positions: HashSet<BeamStep> = HashSet::new();
+    //         let previous_position = x.0;
+    //         let current_position = x.1;
+    //         move_beam(
+    //             contraption,
+    //             current_position,
+    //             previous_position,
+    //             &mut sampled_positions,
+    //         );
+    //         //need to build a hashset of all the positions that the beam has been in
+    //         let mut unique_positions: HashSet<Point> = HashSet::new();
+    //         for (pos1, pos2) in sampled_positions {
+    //             unique_positions.insert(pos1);
+    //             unique_positions.insert(pos2);
+    //         }
+    //         unique_positions.len() as i128
+    //     })
+    //     .collect();
+    for (x, (previous_position, current_position)) in starting_positions.iter().enumerate() {
+        let unique_positions = energized_tiles(contraption, *previous_position, *current_position);
+        trace!("start {}: {} energized", x, unique_positions.len());
+        if unique_positions.len() as i128 > max_unique_positions {
+            max_unique_positions = unique_positions.len() as i128;
+        }
+    }
+    max_unique_positions
+}
+
+/// The contraption with its mirrors in white, its splitters in blue and the
+/// tiles part one's beam energizes highlighted.
+pub fn render(contraption: &Grid<char>) -> Image {
+    let palette = Palette::new(BLACK).with("/\\", WHITE).with("|-", BLUE);
+    let mut image = Image::from_grid(contraption, &palette);
+    let (previous_position, current_position) = TOP_LEFT_START;
+    image.overlay(
+        energized_tiles(contraption, previous_position, current_position),
+        YELLOW,
+    );
+    image
+}
+
+/// A beam spreading through the contraption, for the stepper. Each step
+/// moves every beam front on by one tile, the same way [`move_beam`] searches.
+pub struct BeamWalk<'a> {
+    pub contraption: &'a Grid<char>,
+    pub start: BeamStep,
+}
+
+/// The steps beams have taken so far, and the ones they have just taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Beams {
+    pub taken: HashSet<BeamStep>,
+    pub fronts: Vec<BeamStep>,
+}
+
+impl Simulation for BeamWalk<'_> {
+    type State = Beams;
+
+    fn initial(&self) -> Beams {
+        let fronts: Vec<BeamStep> = [self.start]
+            .into_iter()
+            .filter(|step| in_contraption(self.contraption, *step))
+            .collect();
+        Beams {
+            taken: fronts.iter().copied().collect(),
+            fronts,
+        }
+    }
+
+    fn step(&self, beams: &Beams) -> Option<Beams> {
+        if beams.fronts.is_empty() {
+            return None;
+        }
+        let mut taken = beams.taken.clone();
+        let mut fronts = Vec::new();
+        for step in &beams.fronts {
+            for next in next_beam_steps(self.contraption, *step) {
+                if in_contraption(self.contraption, next) && taken.insert(next) {
+                    fronts.push(next);
+                }
+            }
+        }
+        Some(Beams { taken, fronts })
+    }
+
+    fn draw(&self, beams: &Beams) -> String {
+        let mut drawn = self.contraption.clone();
+        let mut energized: HashSet<Point> = HashSet::new();
+        for (previous_position, current_position) in &beams.taken {
+            energized.insert(*previous_position);
+            energized.insert(*current_position);
+        }
+        for tile in &energized {
+            if let Some(cell @ '.') = drawn.get_mut(tile.x, tile.y) {
+                *cell = '#';
+            }
+        }
+        for (previous_position, current_position) in &beams.fronts {
+            let direction = Direction::from_offset(*current_position - *previous_position);
+            if let (Some(cell), Some(direction)) = (
+                drawn.get_mut(current_position.x, current_position.y),
+                direction,
+            ) {
+                *cell = direction.arrow();
+            }
+        }
+        format!("{}\n\nenergized: {}", drawn, energized.len())
+    }
+}
+
+/// A `size` by `size` contraption of empty space where about one tile in
+/// eight is a mirror or a splitter.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let side = size.max(1);
+    let tiles = [('.', 28), ('/', 1), ('\\', 1), ('|', 1), ('-', 1)];
+    let mut contraption = Grid::new(side, side, '.');
+    for position in contraption.positions().collect::<Vec<(usize, usize)>>() {
+        contraption[position] = weighted(rng, &tiles);
+    }
+    format!("{}\n", contraption)
+}
+
+/// Solves day 16 through the [`Solution`] trait.
+pub struct DaySixteen;
+
+impl Solution for DaySixteen {
+    type Input<'a> = Grid<char>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        Grid::parse(lines)
+    }
+
+    fn part_one(contraption: &Self::Input<'_>) -> Answer {
+        part_one(contraption).into()
+    }
+
+    fn part_two(contraption: &Self::Input<'_>) -> Answer {
+        part_two(contraption).into()
+    }
+
+    fn render(contraption: &Self::Input<'_>) -> Option<Image> {
+        Some(render(contraption))
+    }
+
+    fn stepper<'b>(contraption: &'b Self::Input<'_>) -> Option<Box<dyn Playback + 'b>> {
+        let start = TOP_LEFT_START;
+        Some(Box::new(Stepper::new(BeamWalk { contraption, start })))
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_16_test_input.txt").unwrap();
+    let lines = input.lines();
+    println!("{:?}", lines);
+    assert_eq!(part_one(&Grid::parse(&lines).unwrap()), 46);
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_16_test_input.txt").unwrap();
+    let lines = input.lines();
+    println!("{:?}", lines);
+    assert_eq!(part_two(&Grid::parse(&lines).unwrap()), 51);
+}
+
+#[test]
+fn test_part_one_shortened() {
+    let input = Input::from_data_file("data/day_16_test_input_shortened.txt").unwrap();
+    let lines = input.lines();
+    println!("{:?}", lines);
+    assert_eq!(part_one(&Grid::parse(&lines).unwrap()), 8);
+}
+
+#[test]
+fn test_part_one_mirror_in_top_left() {
+    let lines = vec!["\\...", "....", "...."];
+    assert_eq!(part_one(&Grid::parse(&lines).unwrap()), 3);
+}
+
+#[test]
+fn test_beam_directions() {
+    assert_eq!(beam_directions('/', Direction::Right), vec![Direction::Up]);
+    assert_eq!(beam_directions('/', Direction::Down), vec![Direction::Left]);
+    assert_eq!(
+        beam_directions('\\', Direction::Right),
+        vec![Direction::Down]
+    );
+    assert_eq!(beam_directions('\\', Direction::Up), vec![Direction::Left]);
+    assert_eq!(beam_directions('-', Direction::Left), vec![Direction::Left]);
+    assert_eq!(
+        beam_directions('|', Direction::Right),
+        vec![Direction::Down, Direction::Up]
+    );
+}
+
+#[test]
+fn test_beam_walk() {
+    let contraption = Input::from_data_file("data/day_16_test_input.txt")
+        .unwrap()
+        .grid()
+        .unwrap();
+    let start = (Point::new(0, 0), Point::new(1, 0));
+    let mut stepper = Stepper::new(BeamWalk {
+        contraption: &contraption,
+        start,
+    });
+    assert!(stepper.frame().starts_with("#>...\\....\n"));
+    let beams = stepper.run(usize::MAX).clone();
+    assert!(stepper.finished());
+    assert!(beams.fronts.is_empty());
+    let mut expected: HashSet<BeamStep> = HashSet::new();
+    move_beam(&contraption, start.1, start.0, &mut expected);
+    assert_eq!(beams.taken, expected);
+    assert!(stepper.frame().ends_with("energized: 46"));
+}
@@ -0,0 +1,431 @@
+//! Day 18: Lavaduct Lagoon
+//!
+//! The dig plan traces the edge of a lagoon one trench at a time; the answer
+//! is how many cubic metres the dug-out lagoon holds.
+
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::advent_utils::{column_of, end_column, parse_lines, parse_number_at};
+use crate::error::AocError;
+use crate::generate::{loop_steps, rectilinear_loop, smaller_rectilinear_loops};
+use crate::geometry::{enclosed_points, orientation, Orientation};
+use crate::graph::bfs;
+#[cfg(test)]
+use crate::input::Input;
+use crate::point::{Direction, Point};
+use crate::render::{Colour, Image, BLACK, GREY};
+use crate::solution::{Answer, Solution};
+
+/// One line of the plan, like `R 6 (#70c710)`.
+#[derive(Debug)]
+pub struct DigPlanStep {
+    pub direction: Direction,
+    pub distance: i128,
+    /// The colour in brackets, including the brackets.
+    pub color_code: String,
+}
+
+impl DigPlanStep {
+    /// Parse a step, reporting where the line stopped looking like one.
+    pub fn from_line(line: &str) -> Result<DigPlanStep, AocError> {
+        let line_split: Vec<&str> = line.split(' ').collect();
+        if line_split.len() != 3 {
+            return Err(
+                AocError::parse("expected a direction, a distance and a colour code")
+                    .at_column(end_column(line)),
+            );
+        }
+        let mut letters = line_split[0].chars();
+        let direction = letters
+            .next()
+            .map_or_else(
+                || Err(AocError::parse("expected a direction")),
+                Direction::from_char,
+            )
+            .map_err(|error| error.at_column(1))?;
+        if letters.next().is_some() {
+            return Err(AocError::parse("expected a space after the direction").at_column(2));
+        }
+        let distance: i128 = parse_number_at(line, line_split[1])?;
+        decode_color_code(line_split[2])
+            .map_err(|error| error.at_column(column_of(line, line_split[2])))?;
+        let color_code: String = line_split[2].to_string();
+        Ok(DigPlanStep {
+            direction,
+            distance,
+            color_code,
+        })
+    }
+
+    /// The direction and distance hidden in the colour code: five hex digits of
+    /// distance, then a digit for the direction.
+    pub fn color_instruction(&self) -> (Direction, i128) {
+        decode_color_code(&self.color_code).expect("colour codes are checked when a step is parsed")
+    }
+
+    /// The colour the trench is painted along this step.
+    pub fn colour(&self) -> Colour {
+        let channel = |start: usize| {
+            u8::from_str_radix(&self.color_code[start..start + 2], 16)
+                .expect("colour codes are checked when a step is parsed")
+        };
+        [channel(2), channel(4), channel(6)]
+    }
+}
+
+fn decode_color_code(color_code: &str) -> Result<(Direction, i128), AocError> {
+    let digits = color_code
+        .strip_prefix("(#")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .filter(|digits| digits.len() == 6 && digits.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| {
+            AocError::parse(format!(
+                "expected a colour code like `(#70c710)`, found `{}`",
+                color_code
+            ))
+        })?;
+    let distance = i128::from_str_radix(&digits[..5], 16).expect("checked to be hex digits");
+    let direction = match &digits[5..] {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        other => {
+            return Err(AocError::parse(format!(
+                "expected the colour code to end in a direction from 0 to 3, found `{}`",
+                other
+            )))
+        }
+    };
+    Ok((direction, distance))
+}
+
+/// Parse every step of the plan.
+pub fn get_dig_plan(lines: &[&str]) -> Result<Vec<DigPlanStep>, AocError> {
+    parse_lines(lines, DigPlanStep::from_line)
+}
+
+/// How many cubes a trench through `vertices`, in order and back to the
+/// first, digs out once the lagoon inside it is dug out too.
+pub fn shoelace_area(vertices: &[Point]) -> i128 {
+    enclosed_points(vertices)
+}
+
+/// The longest trench [`trench_vertices`] will follow. No corner of a trench
+/// this long, nor any sum in the shoelace formula over its corners, overflows.
+const LONGEST_TRENCH: i64 = i64::MAX / 4;
+
+/// The corners of the trench the plan digs, starting from the origin, or
+/// `None` if the trench is longer than [`LONGEST_TRENCH`].
+fn trench_vertices(steps: impl Iterator<Item = (Direction, i128)>) -> Option<Vec<Point>> {
+    let mut dig_coordinates = Point::ORIGIN;
+    let mut vertices = vec![dig_coordinates];
+    let mut length: i64 = 0;
+    for (direction, distance) in steps {
+        let distance = i64::try_from(distance).ok()?;
+        length = length
+            .checked_add(distance.abs())
+            .filter(|length| *length <= LONGEST_TRENCH)?;
+        dig_coordinates = dig_coordinates.step_by(direction, distance);
+        vertices.push(dig_coordinates);
+    }
+    Some(vertices)
+}
+
+/// How many cubic metres of lava the lagoon holds, or `None` if the trench
+/// is too long to follow.
+pub fn part_one(dig_plan: &[DigPlanStep]) -> Option<i128> {
+    let vertices = trench_vertices(
+        dig_plan
+            .iter()
+            .map(|dig_plan_step| (dig_plan_step.direction, dig_plan_step.distance)),
+    )?;
+    Some(shoelace_area(&vertices))
+}
+
+/// How many cubic metres the lagoon holds when the real instructions are the
+/// ones hidden in the colour codes.
+pub fn part_two(dig_plan: &[DigPlanStep]) -> Option<i128> {
+    let vertices = trench_vertices(dig_plan.iter().map(DigPlanStep::color_instruction))?;
+    Some(shoelace_area(&vertices))
+}
+
+/// Part one's lagoon dug one cube at a time, in a box with a border of ground
+/// all round so that everything outside the trench is connected.
+struct Lagoon {
+    width: i64,
+    height: i64,
+    /// Each cube of the trench, from the box's top left corner, and the colour
+    /// it is painted.
+    trench: Vec<(Point, Colour)>,
+    /// The ground outside the trench, found by flood filling from the corner.
+    outside: HashSet<Point>,
+}
+
+impl Lagoon {
+    fn dig(dig_plan: &[DigPlanStep]) -> Lagoon {
+        let mut position = Point::ORIGIN;
+        let mut trench: Vec<(Point, Colour)> = vec![(position, BLACK)];
+        for dig_plan_step in dig_plan {
+            for _ in 0..dig_plan_step.distance {
+                position = position.step(dig_plan_step.direction);
+                trench.push((position, dig_plan_step.colour()));
+            }
+        }
+        let min_x = trench.iter().map(|(point, _)| point.x).min().unwrap() - 1;
+        let min_y = trench.iter().map(|(point, _)| point.y).min().unwrap() - 1;
+        let max_x = trench.iter().map(|(point, _)| point.x).max().unwrap() + 1;
+        let max_y = trench.iter().map(|(point, _)| point.y).max().unwrap() + 1;
+        let corner = Point::new(min_x, min_y);
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+        let trench: Vec<(Point, Colour)> = trench
+            .into_iter()
+            .map(|(point, colour)| (point - corner, colour))
+            .collect();
+        let dug: HashSet<Point> = trench.iter().map(|(point, _)| *point).collect();
+        let in_box =
+            |point: &Point| (0..width).contains(&point.x) && (0..height).contains(&point.y);
+        let outside = bfs([Point::ORIGIN], |point| {
+            point
+                .neighbours()
+                .into_iter()
+                .filter(|next| in_box(next) && !dug.contains(next))
+                .collect::<Vec<Point>>()
+        });
+        Lagoon {
+            width,
+            height,
+            trench,
+            outside: outside.reached().copied().collect(),
+        }
+    }
+}
+
+/// How many cubes part one digs out, counted by flood filling the ground
+/// outside the trench rather than with [`shoelace_area`]. It is much slower,
+/// but shares none of its arithmetic, so the two can check each other.
+pub fn flood_fill_area(dig_plan: &[DigPlanStep]) -> i128 {
+    let lagoon = Lagoon::dig(dig_plan);
+    i128::from(lagoon.width * lagoon.height) - lagoon.outside.len() as i128
+}
+
+/// The lagoon part one digs, one pixel per cube: the trench in the colours
+/// the plan paints it and the lagoon inside it in grey.
+pub fn render(dig_plan: &[DigPlanStep]) -> Image {
+    let lagoon = Lagoon::dig(dig_plan);
+    let mut image = Image::new(lagoon.width as usize, lagoon.height as usize, GREY);
+    for point in &lagoon.outside {
+        image.paint(*point, BLACK);
+    }
+    for (point, colour) in lagoon.trench {
+        image.paint(point, colour);
+    }
+    image
+}
+
+/// A dig plan around a [`rectilinear_loop`] with `size` columns, starting at
+/// a random corner and going either way round. The colour codes dig the same
+/// loop scaled up by a random factor that keeps each distance within five hex
+/// digits, so part two's lagoon is part one's made larger.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut vertices = rectilinear_loop(rng, size, 10);
+    let start = rng.gen_range(0..vertices.len());
+    vertices.rotate_left(start);
+    if rng.gen_bool(0.5) {
+        vertices.reverse();
+    }
+    let longest = loop_steps(&vertices)
+        .into_iter()
+        .map(|(_, distance)| distance)
+        .max()
+        .unwrap_or(1);
+    let scale = rng.gen_range(1..=0xfffff / longest);
+    dig_plan_text(&vertices, scale)
+}
+
+/// The dig plan that goes round `vertices`, with colour codes that dig each
+/// edge `scale` times as far.
+fn dig_plan_text(vertices: &[Point], scale: i64) -> String {
+    let mut text = String::new();
+    for (direction, distance) in loop_steps(vertices) {
+        let (letter, digit) = match direction {
+            Direction::Right => ('R', 0),
+            Direction::Down => ('D', 1),
+            Direction::Left => ('L', 2),
+            Direction::Up => ('U', 3),
+        };
+        text.push_str(&format!(
+            "{} {} (#{:05x}{})\n",
+            letter,
+            distance,
+            distance * scale,
+            digit
+        ));
+    }
+    text
+}
+
+/// Smaller dig plans to try in place of `input`, for cross-checking: the loop
+/// it digs made smaller in each way [`smaller_rectilinear_loops`] knows, going
+/// the same way round, with colour codes that dig the same loop. A plan
+/// [`generate_input`] could not have made gives none.
+pub fn shrink_input(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Ok(dig_plan) = get_dig_plan(&lines) else {
+        return Vec::new();
+    };
+    let Some(mut vertices) = trench_vertices(
+        dig_plan
+            .iter()
+            .map(|dig_plan_step| (dig_plan_step.direction, dig_plan_step.distance)),
+    ) else {
+        return Vec::new();
+    };
+    // a closed loop ends where it started
+    if vertices.first() != vertices.last() {
+        return Vec::new();
+    }
+    vertices.pop();
+    let counterclockwise = orientation(&vertices) == Orientation::Counterclockwise;
+    smaller_rectilinear_loops(&vertices)
+        .into_iter()
+        .map(|mut smaller| {
+            if counterclockwise {
+                smaller.reverse();
+            }
+            dig_plan_text(&smaller, 1)
+        })
+        .collect()
+}
+
+/// Solves day 18 through the [`Solution`] trait.
+pub struct DayEighteen;
+
+impl Solution for DayEighteen {
+    type Input<'a> = Vec<DigPlanStep>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_dig_plan(lines)
+    }
+
+    fn part_one(dig_plan: &Self::Input<'_>) -> Answer {
+        part_one(dig_plan).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part_two(dig_plan: &Self::Input<'_>) -> Answer {
+        part_two(dig_plan).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn render(dig_plan: &Self::Input<'_>) -> Option<Image> {
+        Some(render(dig_plan))
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_18_test.txt").unwrap();
+    let lines = input.lines();
+    //36353 is too high
+    let result = part_one(&get_dig_plan(&lines).unwrap());
+    assert_eq!(result, Some(62));
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_18_test.txt").unwrap();
+    let lines = input.lines();
+    let result = part_two(&get_dig_plan(&lines).unwrap());
+    assert_eq!(result, Some(952408144115));
+}
+
+#[test]
+fn test_trench_too_long_to_follow() {
+    let dig_plan = get_dig_plan(&["R 99999999999999999999 (#000000)"]).unwrap();
+    assert_eq!(DayEighteen::part_one(&dig_plan), Answer::Unsolved);
+    let lines: Vec<&str> = vec!["R 9223372036854775807 (#000000)", "R 1 (#000000)"];
+    let dig_plan = get_dig_plan(&lines).unwrap();
+    assert_eq!(DayEighteen::part_one(&dig_plan), Answer::Unsolved);
+    assert_eq!(DayEighteen::part_two(&dig_plan), Answer::Number(1));
+}
+
+#[test]
+fn test_dig_plan_from_malformed_lines() {
+    let error = DigPlanStep::from_line("X 6 (#70c710)").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected a direction of `U`, `R`, `D` or `L`, found `X`"
+    );
+    let error = get_dig_plan(&["RR 6 (#70c710)"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:2: expected a space after the direction"
+    );
+    let lines: Vec<&str> = vec!["R 6 (#70c710)", "D five (#0dc571)"];
+    let error = get_dig_plan(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2:3: expected a number, found `five`"
+    );
+    let error = get_dig_plan(&["R 6 (#70c714)"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:5: expected the colour code to end in a direction from 0 to 3, found `4`"
+    );
+    let error = DigPlanStep::from_line("R 6 (70c710)").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected a colour code like `(#70c710)`, found `(70c710)`"
+    );
+    let error = DigPlanStep::from_line("R 6").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected a direction, a distance and a colour code"
+    );
+}
+
+#[test]
+fn test_shoelace_area() {
+    let cleared_cubes: Vec<Point> = vec![
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(1, 1),
+        Point::new(0, 1),
+    ];
+    let result = shoelace_area(&cleared_cubes);
+    assert_eq!(result, 4);
+    let cleared_cubes: Vec<Point> = vec![
+        Point::new(0, 0),
+        Point::new(2, 0),
+        Point::new(2, 1),
+        Point::new(0, 1),
+    ];
+    let result = shoelace_area(&cleared_cubes);
+    assert_eq!(result, 6);
+}
+
+#[test]
+fn test_render() {
+    let input = Input::from_data_file("data/day_18_test.txt").unwrap();
+    let dig_plan = get_dig_plan(&input.lines()).unwrap();
+    let image = render(&dig_plan);
+    // the example digs a 7 by 10 lagoon, drawn with a border of ground
+    assert_eq!((image.width(), image.height()), (9, 12));
+    let dug = (0..9)
+        .flat_map(|x| (0..12).map(move |y| Point::new(x, y)))
+        .filter(|point| image.get(*point) != Some(BLACK))
+        .count();
+    assert_eq!(Some(dug as i128), part_one(&dig_plan));
+    assert_eq!(image.get(Point::new(2, 1)), Some([0x70, 0xc7, 0x10]));
+    assert_eq!(image.get(Point::new(4, 3)), Some(GREY));
+}
+
+#[test]
+fn test_flood_fill_area() {
+    let input = Input::from_data_file("data/day_18_test.txt").unwrap();
+    let dig_plan = get_dig_plan(&input.lines()).unwrap();
+    assert_eq!(flood_fill_area(&dig_plan), 62);
+}
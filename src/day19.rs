@@ -0,0 +1,577 @@
+//! Day 19: Aplenty
+//!
+//! Each part is sent through named workflows of rules until it is accepted
+//! (`A`) or rejected (`R`).
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::advent_utils::{
+    column_of, end_column, parse_lines, parse_number, parse_number_at, sections, split_any,
+};
+use crate::error::AocError;
+use crate::generate::unique_names;
+use crate::graph::topological_sort;
+#[cfg(test)]
+use crate::input::Input;
+use crate::intervals::{Interval, RangeSet};
+use crate::solution::{Answer, Solution};
+
+/// A named list of rules, like `px{a<2006:qkq,m>2090:A,rfg}`.
+#[derive(Debug)]
+pub struct Workflow {
+    pub id: String,
+    pub rules: Vec<Rule>,
+    /// Where parts go when no rule matches.
+    pub no_match_return: String,
+}
+
+/// One rule of a workflow, like `a<2006:qkq`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Rule {
+    /// `<` or `>`.
+    pub operator: char,
+    /// The rating the part is compared with.
+    pub operands: i64,
+    /// Which rating to compare: `x`, `m`, `a` or `s`.
+    pub part_variable: char,
+    /// Where matching parts go: another workflow, `A` or `R`.
+    pub return_value: String,
+}
+
+impl Rule {
+    /// Parse a rule such as `a<2006:qkq`.
+    pub fn from_line(line: &str) -> Result<Rule, AocError> {
+        let (condition, return_value) = line
+            .split_once(':')
+            .ok_or_else(|| AocError::parse(format!("expected `:` in rule `{}`", line)))?;
+        let mut condition_chars = condition.chars();
+        let part_variable = match condition_chars.next() {
+            Some(c @ ('x' | 'm' | 'a' | 's')) => c,
+            _ => {
+                return Err(AocError::parse(format!(
+                    "expected rule `{}` to start with `x`, `m`, `a` or `s`",
+                    line
+                )))
+            }
+        };
+        let operator = match condition_chars.next() {
+            Some(c @ ('<' | '>')) => c,
+            _ => {
+                return Err(AocError::parse(format!(
+                    "expected `<` or `>` in rule `{}`",
+                    line
+                )))
+            }
+        };
+        let operands = parse_number::<i64>(condition_chars.as_str())?;
+        Ok(Rule {
+            operator,
+            operands,
+            part_variable,
+            return_value: return_value.to_string(),
+        })
+    }
+}
+
+impl Rule {
+    /// Whether `part` matches this rule.
+    pub fn evaluate(&self, part: &Part) -> bool {
+        let part_operand = match self.part_variable {
+            'x' => part.x,
+            'm' => part.m,
+            'a' => part.a,
+            's' => part.s,
+            _ => panic!("Invalid part variable"),
+        };
+
+        let operand = i128::from(self.operands);
+        match self.operator {
+            '<' => part_operand < operand,
+            '>' => part_operand > operand,
+            '=' => part_operand == operand,
+            _ => panic!("Invalid operator"),
+        }
+    }
+}
+
+impl Workflow {
+    /// Parse a workflow, pointing errors in a rule at that rule.
+    pub fn from_line(line: &str) -> Result<Workflow, AocError> {
+        if !line.ends_with('}') || !line.contains('{') {
+            return Err(
+                AocError::parse("expected a workflow like `px{a<2006:qkq,rfg}`")
+                    .at_column(end_column(line)),
+            );
+        }
+        if line.starts_with('{') {
+            return Err(AocError::parse("expected a workflow name before `{`").at_column(1));
+        }
+        // the name, then each rule, then where parts go when no rule matches
+        let pieces = split_any(line, &["{", "}", ","]);
+        let [id, raw_rules @ .., no_match_return] = &pieces[..] else {
+            return Err(
+                AocError::parse("expected where parts go when no rule matches")
+                    .at_column(end_column(line)),
+            );
+        };
+        let mut rules = Vec::new();
+        for raw_rule in raw_rules {
+            let rule = Rule::from_line(raw_rule)
+                .map_err(|error| error.at_column(column_of(line, raw_rule)))?;
+            rules.push(rule);
+        }
+        Ok(Workflow {
+            id: id.to_string(),
+            rules,
+            no_match_return: no_match_return.to_string(),
+        })
+    }
+}
+
+impl Workflow {
+    /// Where this workflow sends `part`.
+    pub fn evaluate(&self, part: &Part) -> String {
+        for rule in &self.rules {
+            if rule.evaluate(part) {
+                return rule.return_value.clone();
+            }
+        }
+        self.no_match_return.clone()
+    }
+}
+
+/// Send `part` from `current_workflow` through `workflows` until it is accepted
+/// or rejected, returning `A` or `R`.
+pub fn run_workflows(current_workflow: &Workflow, workflows: &[Workflow], part: &Part) -> String {
+    for rule in current_workflow.rules.iter() {
+        if rule.evaluate(part) {
+            if rule.return_value == "A" || rule.return_value == "R" {
+                return rule.return_value.as_str().to_string();
+            }
+            let next_workflow = workflows
+                .iter()
+                .find(|workflow| workflow.id == rule.return_value)
+                .unwrap();
+            return run_workflows(next_workflow, workflows, part);
+        }
+    }
+    if current_workflow.no_match_return == "A" || current_workflow.no_match_return == "R" {
+        current_workflow.no_match_return.clone()
+    } else {
+        run_workflows(
+            workflows
+                .iter()
+                .find(|workflow| workflow.id == current_workflow.no_match_return)
+                .unwrap(),
+            workflows,
+            part,
+        )
+    }
+}
+
+/// A part's four ratings, like `{x=787,m=2655,a=1222,s=2876}`.
+#[derive(PartialEq, Debug)]
+pub struct Part {
+    pub x: i128,
+    pub m: i128,
+    pub a: i128,
+    pub s: i128,
+}
+
+impl Part {
+    /// Parse a part, checking it has all four ratings in order.
+    pub fn from_line(line: &str) -> Result<Part, AocError> {
+        let ratings = line
+            .strip_prefix('{')
+            .and_then(|line| line.strip_suffix('}'))
+            .ok_or_else(|| {
+                AocError::parse("expected a part wrapped in `{` and `}`").at_column(1)
+            })?;
+        let line_split: Vec<&str> = ratings.split(',').collect();
+        if line_split.len() != 4 {
+            return Err(
+                AocError::parse(format!("expected 4 ratings, found {}", line_split.len()))
+                    .at_column(end_column(line)),
+            );
+        }
+        let mut values: [i128; 4] = [0; 4];
+        for (ix, (part, name)) in line_split.iter().zip(["x=", "m=", "a=", "s="]).enumerate() {
+            let value = part.strip_prefix(name).ok_or_else(|| {
+                AocError::parse(format!("expected rating `{}`, found `{}`", name, part))
+                    .at_column(column_of(line, part))
+            })?;
+            values[ix] = parse_number_at(line, value)?;
+        }
+        let [x, m, a, s] = values;
+        Ok(Part { x, m, a, s })
+    }
+}
+
+impl Part {
+    /// The sum of the four ratings.
+    pub fn sum(&self) -> i128 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+/// The workflows and the parts listed after them, separated by a blank line.
+/// Every workflow a rule sends parts to is known to exist.
+#[derive(Debug)]
+pub struct System {
+    workflows: Vec<Workflow>,
+    parts: Vec<Part>,
+}
+
+impl System {
+    pub fn workflows(&self) -> &[Workflow] {
+        &self.workflows
+    }
+
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+}
+
+/// Parse the workflows and parts, checking there is an `in` workflow, that
+/// every rule sends parts somewhere that exists, and that no part can go
+/// round in a loop.
+pub fn get_system(lines: &[&str]) -> Result<System, AocError> {
+    let blocks = sections(lines);
+    // the workflows come first, and every later section is parts
+    let (workflow_start, workflow_lines) = blocks.first().copied().unwrap_or((0, &[]));
+    let workflows = parse_lines(workflow_lines, Workflow::from_line)
+        .map_err(|error| error.after_line(workflow_start))?;
+    let mut parts = Vec::new();
+    for (start, part_lines) in blocks.iter().skip(1) {
+        parts.extend(
+            parse_lines(part_lines, Part::from_line).map_err(|error| error.after_line(*start))?,
+        );
+    }
+    let at_workflow = |error: AocError, index: usize| {
+        error.at_line(workflow_start + index + 1, workflow_lines[index])
+    };
+    if !workflows.iter().any(|workflow| workflow.id == "in") {
+        return Err(AocError::parse("expected a workflow named `in`"));
+    }
+    for (index, workflow) in workflows.iter().enumerate() {
+        let targets = workflow
+            .rules
+            .iter()
+            .map(|rule| &rule.return_value)
+            .chain([&workflow.no_match_return]);
+        for target in targets {
+            if target != "A" && target != "R" && !workflows.iter().any(|w| &w.id == target) {
+                let error = AocError::parse(format!(
+                    "workflow `{}` sends parts to unknown workflow `{}`",
+                    workflow.id, target
+                ));
+                return Err(at_workflow(error, index));
+            }
+        }
+    }
+    let workflows_by_id: HashMap<&str, &Workflow> = workflows
+        .iter()
+        .map(|workflow| (workflow.id.as_str(), workflow))
+        .collect();
+    let sorted = topological_sort(workflows_by_id.keys().copied(), |id| {
+        let workflow = workflows_by_id[id];
+        workflow
+            .rules
+            .iter()
+            .map(|rule| rule.return_value.as_str())
+            .chain([workflow.no_match_return.as_str()])
+            .collect::<Vec<&str>>()
+    });
+    if let Err(id) = sorted {
+        let index = workflows
+            .iter()
+            .position(|workflow| workflow.id == id)
+            .unwrap();
+        let error = AocError::parse(format!("workflow `{}` can send parts back to itself", id));
+        return Err(at_workflow(error, index));
+    }
+    Ok(System { workflows, parts })
+}
+
+/// Sum the ratings of every accepted part.
+pub fn part_one(system: &System) -> i128 {
+    let mut answer = 0;
+    let workflows = &system.workflows;
+    let parts = &system.parts;
+    let starting_workflow = workflows
+        .iter()
+        .find(|workflow| workflow.id == "in")
+        .unwrap();
+    for part in parts.iter() {
+        let result = run_workflows(starting_workflow, workflows, part);
+        if result == "A" {
+            answer += part.sum();
+        }
+    }
+    answer
+}
+
+/// The ratings a part could have at some point of the workflows, one set per
+/// rating in `x`, `m`, `a`, `s` order.
+pub type PartRanges = [RangeSet; 4];
+
+fn rating_index(part_variable: char) -> usize {
+    match part_variable {
+        'x' => 0,
+        'm' => 1,
+        'a' => 2,
+        's' => 3,
+        _ => panic!("Invalid part variable"),
+    }
+}
+
+/// How many of the parts in `part_ranges` that are sent to `target` end up accepted.
+pub fn count_accepted(workflows: &[Workflow], target: &str, part_ranges: PartRanges) -> i128 {
+    if part_ranges.iter().any(RangeSet::is_empty) {
+        return 0;
+    }
+    match target {
+        "A" => {
+            return part_ranges
+                .iter()
+                .map(|ratings| ratings.len() as i128)
+                .product()
+        }
+        "R" => return 0,
+        _ => {}
+    }
+    let workflow = workflows
+        .iter()
+        .find(|workflow| workflow.id == target)
+        .unwrap();
+    let mut accepted = 0;
+    let mut remaining = part_ranges;
+    for rule in workflow.rules.iter() {
+        let index = rating_index(rule.part_variable);
+        let operand = rule.operands;
+        let (matching, not_matching) = match rule.operator {
+            '<' => remaining[index].split_at(operand),
+            // no rating is above the largest there is
+            '>' => match operand.checked_add(1) {
+                Some(bound) => {
+                    let (below, above) = remaining[index].split_at(bound);
+                    (above, below)
+                }
+                None => (RangeSet::new(), remaining[index].clone()),
+            },
+            _ => panic!("Invalid operator"),
+        };
+        let mut matching_ranges = remaining.clone();
+        matching_ranges[index] = matching;
+        accepted += count_accepted(workflows, &rule.return_value, matching_ranges);
+        remaining[index] = not_matching;
+    }
+    accepted + count_accepted(workflows, &workflow.no_match_return, remaining)
+}
+
+/// How many combinations of ratings from 1 to 4000 would be accepted.
+pub fn part_two(system: &System) -> i128 {
+    let all_ratings = RangeSet::from(Interval::new(1, 4001));
+    count_accepted(
+        &system.workflows,
+        "in",
+        [
+            all_ratings.clone(),
+            all_ratings.clone(),
+            all_ratings.clone(),
+            all_ratings,
+        ],
+    )
+}
+
+/// `in` and `size` more workflows of one to four rules each, then `size`
+/// parts with ratings from 1 to 4000. Rules only send parts on to workflows
+/// listed after their own in a hidden order that starts with `in`, so no
+/// part can go round in a loop; the workflows are then written out shuffled.
+pub fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut names = vec!["in".to_string()];
+    names.extend(unique_names(
+        rng,
+        size,
+        3,
+        "abcdefghijklmnopqrstuvwxyz",
+        &[],
+    ));
+    let mut workflows: Vec<String> = Vec::with_capacity(names.len());
+    for (index, name) in names.iter().enumerate() {
+        let mut targets: Vec<&str> = names[index + 1..].iter().map(String::as_str).collect();
+        targets.extend(["A", "R"]);
+        let mut rules: Vec<String> = (0..rng.gen_range(1..=4))
+            .map(|_| {
+                format!(
+                    "{}{}{}:{}",
+                    ['x', 'm', 'a', 's']
+                        .choose(rng)
+                        .expect("there are four ratings"),
+                    if rng.gen_bool(0.5) { '<' } else { '>' },
+                    rng.gen_range(1..=4000),
+                    targets.choose(rng).expect("parts can always be accepted"),
+                )
+            })
+            .collect();
+        rules.push(
+            targets
+                .choose(rng)
+                .expect("parts can always be accepted")
+                .to_string(),
+        );
+        workflows.push(format!("{}{{{}}}", name, rules.join(",")));
+    }
+    workflows.shuffle(rng);
+    let parts: Vec<String> = (0..size)
+        .map(|_| {
+            format!(
+                "{{x={},m={},a={},s={}}}",
+                rng.gen_range(1..=4000),
+                rng.gen_range(1..=4000),
+                rng.gen_range(1..=4000),
+                rng.gen_range(1..=4000),
+            )
+        })
+        .collect();
+    format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"))
+}
+
+/// Solves day 19 through the [`Solution`] trait.
+pub struct DayNineteen;
+
+impl Solution for DayNineteen {
+    type Input<'a> = System;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError> {
+        get_system(lines)
+    }
+
+    fn part_one(system: &Self::Input<'_>) -> Answer {
+        part_one(system).into()
+    }
+
+    fn part_two(system: &Self::Input<'_>) -> Answer {
+        part_two(system).into()
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input = Input::from_data_file("data/day_19_test_input.txt").unwrap();
+    let lines = input.lines();
+    assert_eq!(part_one(&get_system(&lines).unwrap()), 19114);
+}
+
+#[test]
+fn test_part_two() {
+    let input = Input::from_data_file("data/day_19_test_input.txt").unwrap();
+    let lines = input.lines();
+    assert_eq!(part_two(&get_system(&lines).unwrap()), 167409079868000);
+}
+
+#[test]
+fn test_part_two_with_the_largest_operand() {
+    let lines: Vec<&str> = vec!["in{x>9223372036854775807:R,A}", "", "{x=1,m=2,a=3,s=4}"];
+    assert_eq!(part_two(&get_system(&lines).unwrap()), 4000_i128.pow(4));
+}
+
+#[test]
+fn test_workflow_from_line() {
+    let line = "px{a<2006:qkq,m>2090:A,rfg}".to_string();
+    let workflow = Workflow::from_line(&line).unwrap();
+    assert_eq!(workflow.id, "px");
+    assert_eq!(workflow.rules.len(), 2);
+    assert_eq!(workflow.rules[0].operator, '<');
+    assert_eq!(workflow.rules[0].operands, 2006);
+    assert_eq!(workflow.rules[0].part_variable, 'a');
+    assert_eq!(workflow.rules[0].return_value, "qkq");
+    assert_eq!(workflow.no_match_return, "rfg")
+}
+
+#[test]
+fn test_part_from_line() {
+    let line = "{x=787,m=2655,a=1222,s=2876}".to_string();
+    let part = Part::from_line(&line).unwrap();
+    assert_eq!(
+        part,
+        Part {
+            x: 787,
+            m: 2655,
+            a: 1222,
+            s: 2876
+        }
+    );
+}
+
+#[test]
+fn test_rule_evaluate() {
+    let rule = Rule {
+        operator: '<',
+        operands: 2006,
+        part_variable: 'a',
+        return_value: "qkq".to_string(),
+    };
+    let part = Part {
+        x: 787,
+        m: 2655,
+        a: 1222,
+        s: 2876,
+    };
+    assert!(rule.evaluate(&part));
+}
+
+#[test]
+fn test_system_from_malformed_lines() {
+    let error = Rule::from_line("a<2006").unwrap_err();
+    assert_eq!(error.to_string(), "expected `:` in rule `a<2006`");
+    let error = Rule::from_line("a=2006:qkq").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected `<` or `>` in rule `a=2006:qkq`"
+    );
+    let error = Workflow::from_line("px{}").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected where parts go when no rule matches"
+    );
+    let error = Workflow::from_line("px{a<2006,rfg}").unwrap_err();
+    assert_eq!(error.to_string(), "expected `:` in rule `a<2006`");
+    assert_eq!(
+        error.at_line(1, "").to_string(),
+        "line 1:4: expected `:` in rule `a<2006`"
+    );
+    let error =
+        get_system(&["in{x>99999999999999999999:A,R}", "", "{x=1,m=2,a=3,s=4}"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1:4: expected a number, found `99999999999999999999`"
+    );
+    let error = Part::from_line("{x=787,m=2655,a=1222}").unwrap_err();
+    assert_eq!(error.to_string(), "expected 4 ratings, found 3");
+    let lines: Vec<&str> = vec![
+        "in{s<1351:px,A}",
+        "px{a<2006:qkq,A}",
+        "",
+        "{x=787,m=2655,a=1222,s=2876}",
+    ];
+    let error = get_system(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2: workflow `px` sends parts to unknown workflow `qkq`"
+    );
+    let lines: Vec<&str> = vec!["in{s<1351:A,R}", "", "{x=787,m=2655,a=1222,s=two}"];
+    let error = get_system(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 3:24: expected a number, found `two`"
+    );
+    let lines: Vec<&str> = vec!["in{s<1351:px,A}", "px{a<2006:qkq,R}", "qkq{x>10:px,A}"];
+    let error = get_system(&lines).unwrap_err();
+    assert!(error.to_string().ends_with("can send parts back to itself"));
+}
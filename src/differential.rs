@@ -0,0 +1,276 @@
+//! Cross-checking the days that solve something two ways.
+//!
+//! A few days keep a simple implementation next to a faster or cleverer one.
+//! Each such [`Pair`] is run on many inputs from the day's `generate_input`,
+//! and [`find_disagreement`] reports the first input on which the two give
+//! different answers, made as small as it can be while they still disagree.
+//!
+//! Day 18's shoelace formula used to sit next to `get_area_cleared_cubes_area`,
+//! which filled each row of dug cubes between the trench walls it crossed. It
+//! was never called, printed every row, and miscounted rows that run along the
+//! trench, so it went when day 18 moved onto [`crate::geometry`]. Its place as
+//! the simple side of the pair is taken by [`day18::flood_fill_area`], which
+//! digs the lagoon cube by cube and counts what a flood from outside can't reach.
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use rand::rngs::StdRng;
+
+use crate::generate::rng_from_seed;
+use crate::{day08, day14, day18};
+
+/// How a disagreeing input can be made smaller.
+#[derive(Debug, Clone, Copy)]
+pub enum Shrink {
+    /// The input only holds together as a whole, so the day gives smaller
+    /// whole inputs to try in its place, and the first that still disagrees is
+    /// taken until none does.
+    Whole(fn(&str) -> Vec<String>),
+    /// Every line is checked on its own, so lines can be dropped, and so can
+    /// characters from the lines that are left.
+    Lines,
+}
+
+/// Two implementations of the same thing, and where to get inputs for them.
+pub struct Pair {
+    pub name: &'static str,
+    pub generate: fn(&mut StdRng, usize) -> String,
+    pub first: fn(&[&str]) -> String,
+    pub second: fn(&[&str]) -> String,
+    pub shrink: Shrink,
+}
+
+/// The answer an implementation gives, or the message it panicked with.
+pub type Outcome = Result<String, String>;
+
+/// An input the two implementations of a [`Pair`] disagree on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub name: &'static str,
+    /// The seed and size of the generated input this was shrunk from.
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub first: Outcome,
+    pub second: Outcome,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} disagree on an input shrunk from seed {} size {}:",
+            self.name, self.seed, self.size
+        )?;
+        writeln!(f, "{}", self.input)?;
+        for (label, outcome) in [("first", &self.first), ("second", &self.second)] {
+            match outcome {
+                Ok(answer) => writeln!(f, "{}: {}", label, answer)?,
+                Err(message) => writeln!(f, "{} panicked: {}", label, message)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Every pair of implementations in the repository.
+pub const PAIRS: [Pair; 3] = [
+    Pair {
+        name: "day 8 part_two and part_two_optimized",
+        generate: day08::generate_input,
+        first: |lines| day08::part_two(&day08_map(lines)).to_string(),
+        second: |lines| match day08::part_two_optimized(&day08_map(lines)) {
+            Some(steps) => steps.to_string(),
+            None => "too many steps for an i64".to_string(),
+        },
+        shrink: Shrink::Whole(day08::shrink_input),
+    },
+    Pair {
+        name: "day 14 tilt_line and tilt_line_simpler",
+        generate: day14::generate_input,
+        first: |lines| tilt_each_line(lines, day14::tilt_line),
+        second: |lines| tilt_each_line(lines, day14::tilt_line_simpler),
+        shrink: Shrink::Lines,
+    },
+    Pair {
+        name: "day 18 shoelace_area and flood_fill_area",
+        generate: day18::generate_input,
+        first: |lines| day18_area(lines, day18::part_one),
+        second: |lines| day18_area(lines, |dig_plan| Some(day18::flood_fill_area(dig_plan))),
+        shrink: Shrink::Whole(day18::shrink_input),
+    },
+];
+
+fn tilt_each_line(lines: &[&str], tilt: fn(&str) -> String) -> String {
+    lines
+        .iter()
+        .map(|line| tilt(line))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn day08_map<'a>(lines: &[&'a str]) -> day08::Map<'a> {
+    day08::get_map(lines).expect("the generated network parses")
+}
+
+fn day18_area(lines: &[&str], area: fn(&[day18::DigPlanStep]) -> Option<i128>) -> String {
+    let dig_plan = day18::get_dig_plan(lines).expect("the generated plan parses");
+    match area(&dig_plan) {
+        Some(area) => area.to_string(),
+        None => "a trench too long to follow".to_string(),
+    }
+}
+
+/// Run `implementation` on `input`, catching a panic as its message.
+fn run(implementation: fn(&[&str]) -> String, input: &str) -> Outcome {
+    let lines: Vec<&str> = input.lines().collect();
+    panic::catch_unwind(AssertUnwindSafe(|| implementation(&lines))).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "a panic without a message".to_string())
+    })
+}
+
+/// Both outcomes on `input`, if they differ.
+fn compare(pair: &Pair, input: &str) -> Option<(Outcome, Outcome)> {
+    let first = run(pair.first, input);
+    let second = run(pair.second, input);
+    (first != second).then_some((first, second))
+}
+
+/// The first input the pair disagrees on, trying every seed at each size
+/// before moving on to the next size up, shrunk as far as the pair allows.
+pub fn find_disagreement(
+    pair: &Pair,
+    seeds: impl IntoIterator<Item = u64> + Clone,
+    sizes: impl IntoIterator<Item = usize>,
+) -> Option<Disagreement> {
+    for size in sizes {
+        for seed in seeds.clone() {
+            let input = (pair.generate)(&mut rng_from_seed(seed), size);
+            if let Some((first, second)) = compare(pair, &input) {
+                let input = match pair.shrink {
+                    Shrink::Whole(smaller) => shrink_whole(pair, input, smaller),
+                    Shrink::Lines => shrink_lines(pair, input),
+                };
+                let (first, second) = compare(pair, &input).unwrap_or((first, second));
+                return Some(Disagreement {
+                    name: pair.name,
+                    seed,
+                    size,
+                    input,
+                    first,
+                    second,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Whether a smaller input still shows the disagreement, rather than only
+/// making one side panic on something it was never meant to see.
+fn still_disagrees(pair: &Pair, input: &str) -> bool {
+    matches!(compare(pair, input), Some((Ok(_), Ok(_))))
+}
+
+/// Swap `input` for the first smaller input that still disagrees, for as long
+/// as there is one.
+fn shrink_whole(pair: &Pair, input: String, smaller: fn(&str) -> Vec<String>) -> String {
+    let mut input = input;
+    while let Some(candidate) = smaller(&input)
+        .into_iter()
+        .find(|candidate| still_disagrees(pair, candidate))
+    {
+        input = candidate;
+    }
+    input
+}
+
+/// Drop whole lines, then single characters, for as long as the pair still
+/// disagrees, until nothing more can go.
+fn shrink_lines(pair: &Pair, input: String) -> String {
+    let mut lines: Vec<String> = input.lines().map(|line| line.to_string()).collect();
+    let mut index = 0;
+    while index < lines.len() {
+        let mut candidate = lines.clone();
+        candidate.remove(index);
+        if still_disagrees(pair, &candidate.join("\n")) {
+            lines = candidate;
+        } else {
+            index += 1;
+        }
+    }
+    for line in 0..lines.len() {
+        let mut index = 0;
+        while index < lines[line].len() {
+            let mut candidate = lines.clone();
+            candidate[line].remove(index);
+            if still_disagrees(pair, &candidate.join("\n")) {
+                lines = candidate;
+            } else {
+                index += 1;
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+#[test]
+fn test_pairs_agree() {
+    for pair in &PAIRS {
+        if let Some(disagreement) = find_disagreement(pair, 0..100, 1..=8) {
+            panic!("{}", disagreement);
+        }
+    }
+}
+
+#[test]
+fn test_find_disagreement_shrinks_lines() {
+    let broken = Pair {
+        name: "tilt_line and not tilting at all",
+        generate: day14::generate_input,
+        first: |lines| tilt_each_line(lines, day14::tilt_line),
+        second: |lines| lines.join("\n"),
+        shrink: Shrink::Lines,
+    };
+    let disagreement = find_disagreement(&broken, 0..10, 1..=8).unwrap();
+    assert_eq!(disagreement.input, ".O");
+    assert_eq!(disagreement.first, Ok("O.".to_string()));
+    assert_eq!(disagreement.second, Ok(".O".to_string()));
+}
+
+#[test]
+fn test_find_disagreement_shrinks_whole_inputs() {
+    let broken = Pair {
+        name: "shoelace_area and one more",
+        generate: day18::generate_input,
+        first: |lines| day18_area(lines, day18::part_one),
+        second: |lines| {
+            day18_area(lines, |dig_plan| {
+                day18::part_one(dig_plan).map(|area| area + 1)
+            })
+        },
+        shrink: Shrink::Whole(day18::shrink_input),
+    };
+    let disagreement = find_disagreement(&broken, 0..10, 1..=8).unwrap();
+    assert_eq!(disagreement.input.lines().count(), 4);
+    assert_eq!(disagreement.first, Ok("4".to_string()));
+
+    let broken = Pair {
+        name: "part_two and one more",
+        generate: day08::generate_input,
+        first: |lines| day08::part_two(&day08_map(lines)).to_string(),
+        second: |lines| (day08::part_two(&day08_map(lines)) + 1).to_string(),
+        shrink: Shrink::Whole(day08::shrink_input),
+    };
+    let disagreement = find_disagreement(&broken, 0..10, 1..=8).unwrap();
+    // one ghost, one instruction and only the nodes the ghost can get to
+    assert_eq!(
+        disagreement.input,
+        "R\n\nCVP = (KBU, RDU)\nKBU = (CVP, CVP)\nNZZ = (RDU, KBU)\nRDU = (CVP, NZZ)\nWDA = (KBU, KBU)\n"
+    );
+}
@@ -0,0 +1,205 @@
+//! The error type shared by input loading, parsing and the command line.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Everything that can go wrong between the command line and a solution.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input could not be read at all.
+    Io { path: String, source: io::Error },
+    /// A data file was not in any of the places it is looked for.
+    NotFound { path: String, tried: Vec<PathBuf> },
+    /// A line of input did not have the shape the parser expected.
+    Parse {
+        path: Option<String>,
+        line: Option<usize>,
+        /// 1-based character column the problem starts at.
+        column: Option<usize>,
+        /// The offending line as it appears in the input.
+        text: Option<String>,
+        reason: String,
+    },
+    /// The command line asked for something that does not exist.
+    Usage(String),
+}
+
+impl AocError {
+    pub fn parse(reason: impl Into<String>) -> AocError {
+        AocError::Parse {
+            path: None,
+            line: None,
+            column: None,
+            text: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// Record the 1-based line number and text a parse error came from, unless it already has them.
+    pub fn at_line(mut self, line_number: usize, line_text: &str) -> AocError {
+        if let AocError::Parse { line, text, .. } = &mut self {
+            if line.is_none() {
+                *line = Some(line_number);
+                *text = Some(line_text.to_string());
+            }
+        }
+        self
+    }
+
+    /// Record the 1-based column a parse error points at, unless it already has one.
+    pub fn at_column(mut self, column_number: usize) -> AocError {
+        if let AocError::Parse { column, .. } = &mut self {
+            column.get_or_insert(column_number);
+        }
+        self
+    }
+
+    /// Move a parse error's line number down by `offset`, for errors from a
+    /// block of lines that starts part way into the input.
+    pub fn after_line(mut self, offset: usize) -> AocError {
+        if let AocError::Parse {
+            line: Some(line), ..
+        } = &mut self
+        {
+            *line += offset;
+        }
+        self
+    }
+
+    /// Record the file a parse error came from, unless it already has one.
+    pub fn in_file(mut self, filepath: &str) -> AocError {
+        if let AocError::Parse { path, .. } = &mut self {
+            path.get_or_insert_with(|| filepath.to_string());
+        }
+        self
+    }
+
+    /// Render the error like a compiler diagnostic: the offending line of input
+    /// with a caret under the bad column and the reason beside it.
+    pub fn diagnostic(&self) -> String {
+        let AocError::Parse {
+            path,
+            line: Some(line),
+            column,
+            text: Some(text),
+            reason,
+        } = self
+        else {
+            return self.to_string();
+        };
+        let mut location = match path {
+            Some(path) => format!("{}:{}", path, line),
+            None => format!("line {}", line),
+        };
+        if let Some(column) = column {
+            location.push_str(&format!(":{}", column));
+        }
+        let gutter = " ".repeat(line.to_string().len());
+        let mut rendered = format!(
+            "could not parse {}\n{} |\n{} | {}\n",
+            location, gutter, line, text
+        );
+        match column {
+            Some(column) => {
+                // keep tabs so the caret lines up however the terminal expands them
+                let padding: String = text
+                    .chars()
+                    .take(column - 1)
+                    .map(|c| if c == '\t' { '\t' } else { ' ' })
+                    .collect();
+                rendered.push_str(&format!("{} | {}^ {}", gutter, padding, reason));
+            }
+            None => rendered.push_str(&format!("{} = {}", gutter, reason)),
+        }
+        rendered
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => write!(f, "could not read {}: {}", path, source),
+            AocError::NotFound { path, tried } => {
+                writeln!(f, "could not find {}, tried:", path)?;
+                for candidate in tried {
+                    writeln!(f, "  {}", candidate.display())?;
+                }
+                write!(
+                    f,
+                    "set {} to the directory holding the puzzle inputs",
+                    crate::data::DATA_DIR_VAR
+                )
+            }
+            AocError::Parse {
+                path,
+                line,
+                column,
+                reason,
+                ..
+            } => {
+                match (path, line) {
+                    (Some(path), Some(line)) => write!(f, "{}:{}", path, line)?,
+                    (Some(path), None) => write!(f, "{}", path)?,
+                    (None, Some(line)) => write!(f, "line {}", line)?,
+                    (None, None) => return write!(f, "{}", reason),
+                }
+                if let (Some(_), Some(column)) = (line, column) {
+                    write!(f, ":{}", column)?;
+                }
+                write!(f, ": {}", reason)
+            }
+            AocError::Usage(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_parse_error_location() {
+    let error = AocError::parse("expected `|`")
+        .at_line(3, "Card 3: 1 2 3")
+        .in_file("cards.txt");
+    assert_eq!(error.to_string(), "cards.txt:3: expected `|`");
+    let error = AocError::parse("expected `|`")
+        .at_line(3, "Card 3: 1 2 3")
+        .at_line(7, "Card 7: 1 2 3");
+    assert_eq!(error.to_string(), "line 3: expected `|`");
+    let error = AocError::parse("expected `|`")
+        .at_column(14)
+        .at_line(3, "Card 3: 1 2 3")
+        .in_file("cards.txt");
+    assert_eq!(error.to_string(), "cards.txt:3:14: expected `|`");
+    let error = AocError::parse("expected `|`")
+        .at_line(3, "Card 3: 1 2 3")
+        .after_line(10);
+    assert_eq!(error.to_string(), "line 13: expected `|`");
+}
+
+#[test]
+fn test_parse_error_diagnostic() {
+    let error = AocError::parse("expected `|` separating winning and played numbers")
+        .at_column(14)
+        .at_line(12, "Card 3: 1 2 3")
+        .in_file("cards.txt");
+    assert_eq!(
+        error.diagnostic(),
+        "could not parse cards.txt:12:14\n   |\n12 | Card 3: 1 2 3\n   |              ^ expected `|` separating winning and played numbers"
+    );
+    let error = AocError::parse("expected a workflow named `in`").at_line(1, "px{A}");
+    assert_eq!(
+        error.diagnostic(),
+        "could not parse line 1\n  |\n1 | px{A}\n  = expected a workflow named `in`"
+    );
+    let error = AocError::parse("expected a number").in_file("cards.txt");
+    assert_eq!(error.diagnostic(), "cards.txt: expected a number");
+}
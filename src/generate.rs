@@ -0,0 +1,340 @@
+//! Random, structurally valid puzzle inputs for exercising the solutions.
+//!
+//! Each day's module has a `generate_input` that writes an input the day
+//! parses, from a seeded [`StdRng`] so a troublesome input can be made again,
+//! and a size that sets how many records or how large a grid there is. The
+//! `aoc gen` command prints them. The helpers here build the pieces several
+//! days share.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::point::{Direction, Point};
+
+/// The size `aoc gen` uses when none is given.
+pub const DEFAULT_SIZE: usize = 10;
+
+pub fn rng_from_seed(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// `count` different strings of `length` characters taken from `alphabet`,
+/// none of them in `reserved`.
+pub fn unique_names(
+    rng: &mut StdRng,
+    count: usize,
+    length: usize,
+    alphabet: &str,
+    reserved: &[&str],
+) -> Vec<String> {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+    let mut names: Vec<String> = Vec::with_capacity(count);
+    while names.len() < count {
+        let name: String = (0..length)
+            .map(|_| *alphabet.choose(rng).expect("the alphabet is not empty"))
+            .collect();
+        if !reserved.contains(&name.as_str()) && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// The corners of a random simple polygon with edges along the grid, going
+/// clockwise on the page from its top left corner, which is at the origin.
+///
+/// The polygon is made of `columns` side by side, each up to `max_width` wide
+/// and holding one run of cells up to `2 * max_width` high, and each run
+/// sharing an edge with the next. Its outline never touches or crosses itself.
+pub fn rectilinear_loop(rng: &mut StdRng, columns: usize, max_width: i64) -> Vec<Point> {
+    let columns = columns.max(1);
+    let max_width = max_width.max(1);
+    let widths: Vec<i64> = (0..columns).map(|_| rng.gen_range(1..=max_width)).collect();
+    // the top and bottom of each column, each overlapping the one before
+    let mut runs: Vec<(i64, i64)> = Vec::with_capacity(columns);
+    let top = rng.gen_range(0..=max_width);
+    runs.push((top, top + rng.gen_range(1..=2 * max_width)));
+    for _ in 1..columns {
+        let (previous_top, previous_bottom) = runs[runs.len() - 1];
+        let top = rng.gen_range(previous_top - max_width..previous_bottom);
+        let bottom = previous_top.max(top) + rng.gen_range(1..=2 * max_width);
+        runs.push((top, bottom));
+    }
+    column_loop(&widths, &runs)
+}
+
+/// The corners of the polygon whose columns are `widths` wide, left to right,
+/// each holding the run of cells from its top to its bottom in `runs`, placed
+/// and ordered as [`rectilinear_loop`] returns them.
+fn column_loop(widths: &[i64], runs: &[(i64, i64)]) -> Vec<Point> {
+    let mut edges_x: Vec<i64> = vec![0];
+    for width in widths {
+        edges_x.push(edges_x[edges_x.len() - 1] + width);
+    }
+    let mut vertices: Vec<Point> = Vec::new();
+    for (column, (top, _)) in runs.iter().enumerate() {
+        vertices.push(Point::new(edges_x[column], *top));
+        vertices.push(Point::new(edges_x[column + 1], *top));
+    }
+    for (column, (_, bottom)) in runs.iter().enumerate().rev() {
+        vertices.push(Point::new(edges_x[column + 1], *bottom));
+        vertices.push(Point::new(edges_x[column], *bottom));
+    }
+    let vertices = corners(&vertices);
+    let min_x = vertices.iter().map(|vertex| vertex.x).min().unwrap_or(0);
+    let min_y = vertices.iter().map(|vertex| vertex.y).min().unwrap_or(0);
+    let origin = Point::new(min_x, min_y);
+    let vertices: Vec<Point> = vertices.into_iter().map(|vertex| vertex - origin).collect();
+    // start from the top left corner, which the first run's left edge begins at
+    let start = vertices
+        .iter()
+        .position(|vertex| *vertex == Point::new(0, runs[0].0 - min_y))
+        .unwrap_or(0);
+    vertices[start..]
+        .iter()
+        .chain(&vertices[..start])
+        .copied()
+        .collect()
+}
+
+/// Whether columns `widths` wide holding `runs` make a polygon
+/// [`rectilinear_loop`] could have made: every column has some width and
+/// some cells, and each run shares an edge with the next.
+fn columns_fit(widths: &[i64], runs: &[(i64, i64)]) -> bool {
+    !runs.is_empty()
+        && widths.iter().all(|width| *width > 0)
+        && runs.iter().all(|(top, bottom)| top < bottom)
+        && runs
+            .windows(2)
+            .all(|pair| pair[1].0 < pair[0].1 && pair[0].0 < pair[1].1)
+}
+
+/// The width of each column of a [`rectilinear_loop`], and the top and bottom
+/// of the run of cells it holds.
+type Columns = (Vec<i64>, Vec<(i64, i64)>);
+
+/// The widths and runs of the columns of a polygon [`rectilinear_loop`] made,
+/// read back from its corners in any order, or `None` if they outline some
+/// other shape.
+fn loop_columns(vertices: &[Point]) -> Option<Columns> {
+    let mut edges_x: Vec<i64> = vertices.iter().map(|vertex| vertex.x).collect();
+    edges_x.sort_unstable();
+    edges_x.dedup();
+    let mut runs: Vec<(i64, i64)> = Vec::new();
+    for edges in edges_x.windows(2) {
+        // the one edge above the column and the one below it
+        let mut crossing: Vec<i64> = vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .filter(|(from, to)| {
+                from.y == to.y && from.x.min(to.x) <= edges[0] && from.x.max(to.x) >= edges[1]
+            })
+            .map(|(from, _)| from.y)
+            .collect();
+        crossing.sort_unstable();
+        match crossing[..] {
+            [top, bottom] => runs.push((top, bottom)),
+            _ => return None,
+        }
+    }
+    let widths: Vec<i64> = edges_x
+        .windows(2)
+        .map(|edges| edges[1] - edges[0])
+        .collect();
+    if !columns_fit(&widths, &runs) {
+        return None;
+    }
+    let min_x = vertices.iter().map(|vertex| vertex.x).min()?;
+    let min_y = vertices.iter().map(|vertex| vertex.y).min()?;
+    let mut found: Vec<Point> = vertices
+        .iter()
+        .map(|vertex| *vertex - Point::new(min_x, min_y))
+        .collect();
+    let mut expected = column_loop(&widths, &runs);
+    found.sort_unstable();
+    expected.sort_unstable();
+    (found == expected).then_some((widths, runs))
+}
+
+/// Smaller polygons than the one a [`rectilinear_loop`] outlined with
+/// `vertices`: each with one column left out or made narrower, or one run made
+/// shorter, as long as every run still shares an edge with the next. Corners
+/// of any other shape give none.
+pub fn smaller_rectilinear_loops(vertices: &[Point]) -> Vec<Vec<Point>> {
+    let Some((widths, runs)) = loop_columns(vertices) else {
+        return Vec::new();
+    };
+    let mut smaller: Vec<Columns> = Vec::new();
+    for column in 0..runs.len() {
+        let (mut fewer_widths, mut fewer_runs) = (widths.clone(), runs.clone());
+        fewer_widths.remove(column);
+        fewer_runs.remove(column);
+        smaller.push((fewer_widths, fewer_runs));
+        let mut narrower = widths.clone();
+        narrower[column] -= 1;
+        smaller.push((narrower, runs.clone()));
+        let (top, bottom) = runs[column];
+        for run in [(top + 1, bottom), (top, bottom - 1)] {
+            let mut shorter = runs.clone();
+            shorter[column] = run;
+            smaller.push((widths.clone(), shorter));
+        }
+    }
+    smaller
+        .into_iter()
+        .filter(|(widths, runs)| columns_fit(widths, runs))
+        .map(|(widths, runs)| column_loop(&widths, &runs))
+        .collect()
+}
+
+/// `vertices` with repeated points and points part way along a straight edge
+/// taken out, so that each is a corner where the outline turns.
+fn corners(vertices: &[Point]) -> Vec<Point> {
+    let mut deduplicated: Vec<Point> = Vec::new();
+    for vertex in vertices {
+        if deduplicated.last() != Some(vertex) {
+            deduplicated.push(*vertex);
+        }
+    }
+    while deduplicated.len() > 1 && deduplicated.first() == deduplicated.last() {
+        deduplicated.pop();
+    }
+    let count = deduplicated.len();
+    (0..count)
+        .filter(|&index| {
+            let previous = deduplicated[(index + count - 1) % count];
+            let next = deduplicated[(index + 1) % count];
+            let vertex = deduplicated[index];
+            let incoming = vertex - previous;
+            let outgoing = next - vertex;
+            incoming.x * outgoing.y != incoming.y * outgoing.x
+        })
+        .map(|index| deduplicated[index])
+        .collect()
+}
+
+/// Each edge of the polygon as the direction it runs in and its length,
+/// starting from the first vertex and ending back at it.
+pub fn loop_steps(vertices: &[Point]) -> Vec<(Direction, i64)> {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(from, to)| {
+            let offset = *to - *from;
+            let distance = offset.x.abs() + offset.y.abs();
+            let unit = Point::new(offset.x.signum(), offset.y.signum());
+            let direction = Direction::from_offset(unit).expect("edges run along the grid");
+            (direction, distance)
+        })
+        .collect()
+}
+
+/// Every lattice point on the polygon's outline in order, starting from the
+/// first vertex and not repeating it at the end.
+pub fn loop_tiles(vertices: &[Point]) -> Vec<Point> {
+    let mut tiles: Vec<Point> = Vec::new();
+    let mut position = vertices.first().copied().unwrap_or(Point::ORIGIN);
+    for (direction, distance) in loop_steps(vertices) {
+        for _ in 0..distance {
+            tiles.push(position);
+            position = position.step(direction);
+        }
+    }
+    tiles
+}
+
+/// Pick one of `choices` by weight.
+pub fn weighted<T: Copy>(rng: &mut StdRng, choices: &[(T, u32)]) -> T {
+    choices
+        .choose_weighted(rng, |(_, weight)| *weight)
+        .expect("the weights are positive")
+        .0
+}
+
+#[cfg(test)]
+use crate::geometry::{enclosed_points, orientation, Orientation};
+#[cfg(test)]
+use crate::registry::DAYS;
+#[cfg(test)]
+use crate::solution::Part;
+#[cfg(test)]
+use std::collections::HashSet;
+
+#[test]
+fn test_rectilinear_loop() {
+    for seed in 0..50 {
+        let mut rng = rng_from_seed(seed);
+        let vertices = rectilinear_loop(&mut rng, 1 + seed as usize % 7, 4);
+        assert_eq!(vertices[0], Point::new(0, vertices[0].y));
+        assert!(vertices.iter().all(|vertex| vertex.x >= 0 && vertex.y >= 0));
+        assert_eq!(orientation(&vertices), Orientation::Clockwise);
+        // the outline turns at every corner and visits each point once
+        let steps = loop_steps(&vertices);
+        assert!(steps.iter().all(|(_, distance)| *distance > 0));
+        assert!(steps
+            .iter()
+            .zip(steps.iter().cycle().skip(1))
+            .all(|((a, _), (b, _))| a != b && *a != b.reverse()));
+        let tiles = loop_tiles(&vertices);
+        let distinct: HashSet<Point> = tiles.iter().copied().collect();
+        assert_eq!(distinct.len(), tiles.len());
+        assert_eq!(
+            tiles.len() as i64,
+            steps.iter().map(|(_, d)| d).sum::<i64>()
+        );
+    }
+}
+
+#[test]
+fn test_smaller_rectilinear_loops() {
+    let mut rng = rng_from_seed(3);
+    let vertices = rectilinear_loop(&mut rng, 4, 3);
+    let (widths, runs) = loop_columns(&vertices).unwrap();
+    assert_eq!(column_loop(&widths, &runs), vertices);
+    let smaller = smaller_rectilinear_loops(&vertices);
+    assert!(!smaller.is_empty());
+    for smaller in smaller {
+        assert!(loop_columns(&smaller).is_some());
+        assert!(enclosed_points(&smaller) < enclosed_points(&vertices));
+    }
+    // a C has two runs in each column to the right of its back
+    let c = [
+        (0, 0),
+        (3, 0),
+        (3, 1),
+        (1, 1),
+        (1, 2),
+        (3, 2),
+        (3, 3),
+        (0, 3),
+    ];
+    let c: Vec<Point> = c.iter().map(|(x, y)| Point::new(*x, *y)).collect();
+    assert!(smaller_rectilinear_loops(&c).is_empty());
+}
+
+#[test]
+fn test_unique_names() {
+    let mut rng = rng_from_seed(7);
+    let mut names = unique_names(&mut rng, 3, 2, "ab", &["aa"]);
+    names.sort();
+    assert_eq!(names, ["ab", "ba", "bb"]);
+}
+
+#[test]
+fn test_generated_inputs_parse_and_solve() {
+    for day in DAYS {
+        for (seed, size) in [(1, 1), (2, 3), (3, DEFAULT_SIZE)] {
+            let text = day.generate(&mut rng_from_seed(seed), size);
+            let lines: Vec<&str> = text.lines().collect();
+            let parsed = day.parse(&lines).unwrap_or_else(|error| {
+                panic!("day {} seed {}: {}\n{}", day.number, seed, error, text)
+            });
+            parsed.solve(Part::One);
+            // day 12's part two does not finish on anything but tiny inputs yet
+            if day.number != 12 {
+                parsed.solve(Part::Two);
+            }
+        }
+    }
+}
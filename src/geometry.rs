@@ -0,0 +1,148 @@
+//! Exact area and lattice point counts for polygons with integer vertices.
+//!
+//! A polygon is the list of its vertices in order, joined back from the last
+//! to the first. Vertices part way along a straight edge, and a copy of the
+//! first vertex at the end, make no difference, so the tiles of a loop walked
+//! one step at a time can be passed in as they are.
+//!
+//! Areas are returned doubled so that they stay exact integers.
+
+use crate::math::gcd;
+use crate::point::Point;
+
+/// Which way a polygon's vertices go round, as seen on the page with `y`
+/// growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    Counterclockwise,
+    /// The polygon encloses no area, such as a line walked there and back.
+    Degenerate,
+}
+
+/// Each edge of the polygon, including the one back to the first vertex.
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+/// Twice the area by the shoelace formula, positive when the vertices go
+/// clockwise on the page.
+pub fn doubled_signed_area(vertices: &[Point]) -> i128 {
+    edges(vertices)
+        .map(|(a, b)| i128::from(a.x) * i128::from(b.y) - i128::from(b.x) * i128::from(a.y))
+        .sum()
+}
+
+/// Twice the area the polygon encloses, whichever way round it goes.
+pub fn doubled_area(vertices: &[Point]) -> i128 {
+    doubled_signed_area(vertices).abs()
+}
+
+pub fn orientation(vertices: &[Point]) -> Orientation {
+    match doubled_signed_area(vertices).signum() {
+        1 => Orientation::Clockwise,
+        -1 => Orientation::Counterclockwise,
+        _ => Orientation::Degenerate,
+    }
+}
+
+/// How many lattice points lie on the edges. Each is counted once for a
+/// simple polygon; edges that retrace each other count their points twice.
+pub fn boundary_points(vertices: &[Point]) -> i128 {
+    edges(vertices)
+        .map(|(a, b)| {
+            let offset = b - a;
+            i128::from(gcd(offset.x, offset.y))
+        })
+        .sum()
+}
+
+/// How many lattice points lie strictly inside a simple polygon, by Pick's
+/// theorem. A degenerate polygon has none.
+pub fn interior_points(vertices: &[Point]) -> i128 {
+    let doubled_area = doubled_area(vertices);
+    if doubled_area == 0 {
+        return 0;
+    }
+    (doubled_area - boundary_points(vertices)) / 2 + 1
+}
+
+/// How many lattice points lie inside or on a simple polygon: the number of
+/// unit tiles covered by a loop walked through the vertices together with
+/// everything it surrounds.
+///
+/// This also counts the tiles of a degenerate polygon whose edges retrace
+/// each other, such as a line walked there and back.
+pub fn enclosed_points(vertices: &[Point]) -> i128 {
+    if vertices.is_empty() {
+        return 0;
+    }
+    (doubled_area(vertices) + boundary_points(vertices)) / 2 + 1
+}
+
+#[test]
+fn test_square() {
+    let square = [
+        Point::new(0, 0),
+        Point::new(4, 0),
+        Point::new(4, 4),
+        Point::new(0, 4),
+    ];
+    assert_eq!(doubled_area(&square), 32);
+    assert_eq!(boundary_points(&square), 16);
+    assert_eq!(interior_points(&square), 9);
+    assert_eq!(enclosed_points(&square), 25);
+    // the same square one lattice point at a time, closed off with the start
+    let walked: Vec<Point> = (0..4)
+        .map(|x| Point::new(x, 0))
+        .chain((0..4).map(|y| Point::new(4, y)))
+        .chain((1..=4).rev().map(|x| Point::new(x, 4)))
+        .chain((1..=4).rev().map(|y| Point::new(0, y)))
+        .chain([Point::new(0, 0)])
+        .collect();
+    assert_eq!(doubled_area(&walked), 32);
+    assert_eq!(boundary_points(&walked), 16);
+    assert_eq!(interior_points(&walked), 9);
+}
+
+#[test]
+fn test_orientation() {
+    let clockwise = [Point::new(0, 0), Point::new(3, 0), Point::new(0, 2)];
+    let counterclockwise: Vec<Point> = clockwise.iter().rev().copied().collect();
+    assert_eq!(orientation(&clockwise), Orientation::Clockwise);
+    assert_eq!(
+        orientation(&counterclockwise),
+        Orientation::Counterclockwise
+    );
+    assert_eq!(doubled_signed_area(&clockwise), 6);
+    assert_eq!(doubled_signed_area(&counterclockwise), -6);
+    // the area and the counts do not depend on the direction
+    assert_eq!(doubled_area(&counterclockwise), 6);
+    assert_eq!(boundary_points(&counterclockwise), 6);
+    assert_eq!(interior_points(&counterclockwise), 1);
+    assert_eq!(enclosed_points(&clockwise), 7);
+    assert_eq!(enclosed_points(&counterclockwise), 7);
+}
+
+#[test]
+fn test_degenerate_polygons() {
+    assert_eq!(orientation(&[]), Orientation::Degenerate);
+    assert_eq!(enclosed_points(&[]), 0);
+    let point = [Point::new(2, 5)];
+    assert_eq!(orientation(&point), Orientation::Degenerate);
+    assert_eq!(boundary_points(&point), 0);
+    assert_eq!(enclosed_points(&point), 1);
+    // a line walked there and back covers its four tiles and surrounds nothing
+    let line = [Point::new(0, 0), Point::new(3, 0)];
+    assert_eq!(orientation(&line), Orientation::Degenerate);
+    assert_eq!(doubled_area(&line), 0);
+    assert_eq!(boundary_points(&line), 6);
+    assert_eq!(interior_points(&line), 0);
+    assert_eq!(enclosed_points(&line), 4);
+    let collinear = [Point::new(0, 0), Point::new(2, 2), Point::new(4, 4)];
+    assert_eq!(orientation(&collinear), Orientation::Degenerate);
+    assert_eq!(enclosed_points(&collinear), 5);
+}
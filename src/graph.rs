@@ -0,0 +1,466 @@
+//! Searches and orderings over graphs given as neighbour functions.
+//!
+//! Nothing here needs the graph written out: a node is any `Clone + Eq +
+//! Hash` state, and its edges come from a closure called on demand. For a
+//! [`Grid`](crate::grid::Grid) the state is a position and the closure is
+//! usually a filtered [`neighbours4`](crate::grid::Grid::neighbours4).
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Everything a search reached: the cost of the cheapest way to each node
+/// and the node it was reached from.
+#[derive(Debug, Clone)]
+pub struct Search<N, C = usize> {
+    costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    order: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    /// The cost of the cheapest way to `node`, or `None` if it was not reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// Every reached node, in the order the search settled them.
+    pub fn reached(&self) -> impl Iterator<Item = &N> + '_ {
+        self.order.iter()
+    }
+
+    /// How many nodes were reached, starts included.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// The cheapest path from a start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.contains(node)
+            .then(|| reconstruct_path(&self.parents, node.clone()))
+    }
+}
+
+/// Follow `parents` back from `end` to a node without a parent, returning the
+/// path in order from that node to `end`.
+pub fn reconstruct_path<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(parent) = parents.get(&path[path.len() - 1]) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Breadth-first search from every node of `starts`, where each step costs 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        costs: HashMap::new(),
+        parents: HashMap::new(),
+        order: Vec::new(),
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let cost = search.costs[&node];
+        for next in neighbours(&node) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), cost + 1);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+        search.order.push(node);
+    }
+    search
+}
+
+/// A node waiting in the priority queue, ordered so the cheapest comes out first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// Best-first search from `starts`, settling nodes in order of cost plus
+/// `heuristic`, and stopping at the first node `is_goal` accepts.
+fn best_first<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Search<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search {
+        costs: HashMap::new(),
+        parents: HashMap::new(),
+        order: Vec::new(),
+    };
+    let mut settled = HashSet::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        search.costs.insert(start.clone(), C::default());
+        queue.push(Reverse(Queued {
+            priority: heuristic(&start),
+            cost: C::default(),
+            node: start,
+        }));
+    }
+    while let Some(Reverse(Queued { cost, node, .. })) = queue.pop() {
+        if !settled.insert(node.clone()) {
+            continue;
+        }
+        search.order.push(node.clone());
+        if is_goal(&node) {
+            return (search, Some(node));
+        }
+        for (next, step_cost) in neighbours(&node) {
+            let next_cost = cost + step_cost;
+            if search
+                .costs
+                .get(&next)
+                .is_none_or(|known| next_cost < *known)
+            {
+                search.costs.insert(next.clone(), next_cost);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push(Reverse(Queued {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                }));
+            }
+        }
+    }
+    // only settled nodes have their final cost
+    search.costs.retain(|node, _| settled.contains(node));
+    search.parents.retain(|node, _| settled.contains(node));
+    (search, None)
+}
+
+/// Dijkstra's algorithm from every node of `starts`, where `neighbours` gives
+/// each next node with the non-negative cost of stepping to it.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(starts, neighbours, |_| C::default(), |_| false).0
+}
+
+/// A* search from `start` to the first node `is_goal` accepts, returning the
+/// cheapest path and its cost. `heuristic` must never overestimate the cost
+/// left to a goal; one that always returns zero makes this Dijkstra's algorithm.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (search, goal) = best_first([start], neighbours, heuristic, is_goal);
+    let goal = goal?;
+    let cost = search.cost(&goal)?;
+    Some((reconstruct_path(&search.parents, goal), cost))
+}
+
+/// Tarjan's strongly connected components of the graph reachable from
+/// `nodes`. Components come out in reverse topological order: nothing in a
+/// component leads to a component listed after it.
+pub fn strongly_connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut index: HashMap<N, usize> = HashMap::new();
+    let mut low_link: HashMap<N, usize> = HashMap::new();
+    let mut on_stack: HashSet<N> = HashSet::new();
+    let mut stack: Vec<N> = Vec::new();
+    let mut components: Vec<Vec<N>> = Vec::new();
+    for root in nodes {
+        if index.contains_key(&root) {
+            continue;
+        }
+        // an explicit call stack of nodes and their unvisited successors, so
+        // long chains cannot overflow the real one
+        let mut call_stack: Vec<(N, std::vec::IntoIter<N>)> = Vec::new();
+        let visit = |node: &N,
+                     index: &mut HashMap<N, usize>,
+                     low_link: &mut HashMap<N, usize>,
+                     on_stack: &mut HashSet<N>,
+                     stack: &mut Vec<N>| {
+            let next_index = index.len();
+            index.insert(node.clone(), next_index);
+            low_link.insert(node.clone(), next_index);
+            on_stack.insert(node.clone());
+            stack.push(node.clone());
+        };
+        visit(&root, &mut index, &mut low_link, &mut on_stack, &mut stack);
+        let root_successors: Vec<N> = successors(&root).into_iter().collect();
+        call_stack.push((root, root_successors.into_iter()));
+        while let Some((node, remaining)) = call_stack.last_mut() {
+            if let Some(next) = remaining.next() {
+                if !index.contains_key(&next) {
+                    visit(&next, &mut index, &mut low_link, &mut on_stack, &mut stack);
+                    let next_successors: Vec<N> = successors(&next).into_iter().collect();
+                    call_stack.push((next, next_successors.into_iter()));
+                } else if on_stack.contains(&next) {
+                    let low = low_link[node].min(index[&next]);
+                    low_link.insert(node.clone(), low);
+                }
+                continue;
+            }
+            let (node, _) = call_stack.pop().unwrap();
+            if low_link[&node] == index[&node] {
+                let mut component = Vec::new();
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack.remove(&member);
+                    let done = member == node;
+                    component.push(member);
+                    if done {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+            if let Some((parent, _)) = call_stack.last() {
+                let low = low_link[parent].min(low_link[&node]);
+                low_link.insert(parent.clone(), low);
+            }
+        }
+    }
+    components
+}
+
+/// Order `nodes` so every node comes before its successors, or return a node
+/// that lies on a cycle. Successors that are not in `nodes` are ignored.
+pub fn topological_sort<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Result<Vec<N>, N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let nodes: Vec<N> = nodes.into_iter().collect();
+    let mut edges: HashMap<N, Vec<N>> = HashMap::new();
+    let mut in_degree: HashMap<N, usize> = nodes.iter().map(|node| (node.clone(), 0)).collect();
+    for node in &nodes {
+        let node_successors: Vec<N> = successors(node)
+            .into_iter()
+            .filter(|next| in_degree.contains_key(next))
+            .collect();
+        for next in &node_successors {
+            *in_degree.get_mut(next).unwrap() += 1;
+        }
+        edges.insert(node.clone(), node_successors);
+    }
+    let mut ready: VecDeque<N> = nodes
+        .iter()
+        .filter(|node| in_degree[*node] == 0)
+        .cloned()
+        .collect();
+    let mut sorted = Vec::with_capacity(nodes.len());
+    while let Some(node) = ready.pop_front() {
+        for next in &edges[&node] {
+            let degree = in_degree.get_mut(next).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                ready.push_back(next.clone());
+            }
+        }
+        sorted.push(node);
+    }
+    if sorted.len() == nodes.len() {
+        return Ok(sorted);
+    }
+    // whatever is left over has an edge into it from a cycle, or is on one
+    let left_over: Vec<N> = nodes
+        .into_iter()
+        .filter(|node| in_degree[node] > 0)
+        .collect();
+    let components =
+        strongly_connected_components(left_over.iter().cloned(), |node| edges[node].clone());
+    let cycle_member = components
+        .into_iter()
+        .find(|component| component.len() > 1 || edges[&component[0]].contains(&component[0]))
+        .map(|component| component[0].clone());
+    Err(cycle_member.unwrap_or_else(|| left_over[0].clone()))
+}
+
+#[cfg(test)]
+use crate::grid::Grid;
+
+#[cfg(test)]
+fn maze() -> Grid<char> {
+    let lines = ["S..#....", ".#.#.##.", ".#...#..", ".####.#.", "......#E"];
+    Grid::parse(&lines).unwrap()
+}
+
+#[test]
+fn test_bfs_on_grid() {
+    let maze = maze();
+    let start = maze.position(|c| *c == 'S').unwrap();
+    let end = maze.position(|c| *c == 'E').unwrap();
+    let search = bfs([start], |&(x, y)| {
+        maze.neighbours4(x, y)
+            .filter(|position| maze[*position] != '#')
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(search.cost(&end), Some(15));
+    let path = search.path_to(&end).unwrap();
+    assert_eq!(path.len(), 16);
+    assert_eq!((path[0], path[15]), (start, end));
+    for pair in path.windows(2) {
+        assert!(maze.neighbours4(pair[0].0, pair[0].1).any(|p| p == pair[1]));
+    }
+    assert_eq!(search.cost(&(3, 0)), None);
+    assert_eq!(search.reached().next(), Some(&start));
+    assert_eq!(search.len(), 27);
+}
+
+#[test]
+fn test_bfs_implicit() {
+    // reach 10 from 1 by doubling or adding one
+    let search = bfs([1_u32], |&n| {
+        [n * 2, n + 1].into_iter().filter(|n| *n <= 10)
+    });
+    assert_eq!(search.cost(&10), Some(4));
+    assert_eq!(search.path_to(&10), Some(vec![1, 2, 4, 5, 10]));
+}
+
+#[test]
+fn test_dijkstra_and_astar() {
+    let edges: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+        ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+        ('b', vec![('a', 7), ('c', 10), ('d', 15)]),
+        ('c', vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)]),
+        ('d', vec![('b', 15), ('c', 11), ('e', 6)]),
+        ('e', vec![('d', 6), ('f', 9)]),
+        ('f', vec![('a', 14), ('c', 2), ('e', 9)]),
+        ('g', vec![]),
+    ]);
+    let search = dijkstra(['a'], |node| edges[node].clone());
+    assert_eq!(search.cost(&'e'), Some(20));
+    assert_eq!(search.cost(&'d'), Some(20));
+    assert_eq!(search.path_to(&'e'), Some(vec!['a', 'c', 'f', 'e']));
+    assert_eq!(search.cost(&'g'), None);
+    let (path, cost) = astar('a', |node| edges[node].clone(), |_| 0, |node| *node == 'e').unwrap();
+    assert_eq!((path, cost), (vec!['a', 'c', 'f', 'e'], 20));
+    assert_eq!(
+        astar('a', |node| edges[node].clone(), |_| 0, |node| *node == 'g'),
+        None
+    );
+}
+
+#[test]
+fn test_astar_on_grid() {
+    let maze = maze();
+    let end = maze.position(|c| *c == 'E').unwrap();
+    let manhattan = |&(x, y): &(usize, usize)| x.abs_diff(end.0) + y.abs_diff(end.1);
+    let (path, cost) = astar(
+        (0, 0),
+        |&(x, y)| {
+            maze.neighbours4(x, y)
+                .filter(|position| maze[*position] != '#')
+                .map(|position| (position, 1))
+                .collect::<Vec<_>>()
+        },
+        manhattan,
+        |position| *position == end,
+    )
+    .unwrap();
+    assert_eq!(cost, 15);
+    assert_eq!(path.len(), 16);
+}
+
+#[test]
+fn test_strongly_connected_components() {
+    let edges: HashMap<u8, Vec<u8>> = HashMap::from([
+        (1, vec![2]),
+        (2, vec![3]),
+        (3, vec![1, 4]),
+        (4, vec![5]),
+        (5, vec![4]),
+        (6, vec![6]),
+    ]);
+    let mut components = strongly_connected_components([1, 6], |node| edges[node].clone());
+    for component in components.iter_mut() {
+        component.sort();
+    }
+    assert_eq!(components, vec![vec![4, 5], vec![1, 2, 3], vec![6]]);
+}
+
+#[test]
+fn test_topological_sort() {
+    let edges: HashMap<&str, Vec<&str>> = HashMap::from([
+        ("in", vec!["px", "qqz"]),
+        ("px", vec!["qkq", "A"]),
+        ("qqz", vec!["qkq"]),
+        ("qkq", vec!["R"]),
+    ]);
+    let sorted = topological_sort(["qkq", "px", "in", "qqz"], |node| edges[node].clone()).unwrap();
+    let position = |node: &str| sorted.iter().position(|n| *n == node).unwrap();
+    assert_eq!(position("in"), 0);
+    assert!(position("px") < position("qkq") && position("qqz") < position("qkq"));
+    let edges: HashMap<u8, Vec<u8>> =
+        HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![2, 4]), (4, vec![])]);
+    let cycle_member = topological_sort([1, 2, 3, 4], |node| edges[node].clone()).unwrap_err();
+    assert!(cycle_member == 2 || cycle_member == 3);
+}
@@ -0,0 +1,381 @@
+//! A rectangular grid of cells, the shape most puzzle inputs come in.
+//!
+//! Positions are `(x, y)`: `x` is the column counting right from the left
+//! edge and `y` is the row counting down from the top line of input.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::AocError;
+
+/// A `width` by `height` grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, AocError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(AocError::parse(format!(
+                    "row {} has {} cells but the first row has {}",
+                    y + 1,
+                    row.len(),
+                    width
+                )));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parse one cell per character of `lines`, pointing any error at the
+    /// character it came from. Every line must be as long as the first.
+    pub fn parse_with(
+        lines: &[&str],
+        parse: impl Fn(char) -> Result<T, AocError>,
+    ) -> Result<Grid<T>, AocError> {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            let mut row_width = 0;
+            for (column, c) in line.chars().enumerate() {
+                let cell = parse(c)
+                    .map_err(|error| error.at_column(column + 1).at_line(index + 1, line))?;
+                cells.push(cell);
+                row_width += 1;
+            }
+            if row_width != width {
+                let column = row_width.min(width) + 1;
+                let error = AocError::parse(format!(
+                    "expected a row of {} cells, found {}",
+                    width, row_width
+                ));
+                return Err(error.at_column(column).at_line(index + 1, line));
+            }
+        }
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(x, y)` lies inside the grid. Takes any integer type, so
+    /// positions that may have stepped off the top or left edge can be checked.
+    pub fn contains<I: TryInto<usize>>(&self, x: I, y: I) -> bool {
+        self.offset(x, y).is_some()
+    }
+
+    fn offset<I: TryInto<usize>>(&self, x: I, y: I) -> Option<usize> {
+        let x: usize = x.try_into().ok()?;
+        let y: usize = y.try_into().ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    /// The cell at `(x, y)`, or `None` if that is outside the grid.
+    pub fn get<I: TryInto<usize>>(&self, x: I, y: I) -> Option<&T> {
+        self.offset(x, y).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut<I: TryInto<usize>>(&mut self, x: I, y: I) -> Option<&mut T> {
+        self.offset(x, y).map(|offset| &mut self.cells[offset])
+    }
+
+    /// The up to four positions above, left of, right of and below `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// The up to eight positions around `(x, y)`, diagonals included, in reading order.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(
+            x,
+            y,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn neighbours(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx)?;
+            let y = y.checked_add_signed(*dy)?;
+            self.contains(x, y).then_some((x, y))
+        })
+    }
+
+    /// Row `y`, left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {} is outside a grid of height {}",
+            y,
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(
+            x < self.width,
+            "column {} is outside a grid of width {}",
+            x,
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every column, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Every position in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every position and its cell, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, in reading order, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// A `width` by `height` grid built from the position of every cell.
+    fn from_fn(width: usize, height: usize, f: impl Fn(usize, usize) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Swap rows and columns, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Turn the grid a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Turn the grid a quarter turn counterclockwise, so the top row becomes the left column.
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirror the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirror the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+}
+
+impl Grid<char> {
+    /// One cell per character of `lines`.
+    pub fn parse(lines: &[&str]) -> Result<Grid<char>, AocError> {
+        Grid::parse_with(lines, Ok)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.offset(x, y) {
+            Some(offset) => &self.cells[offset],
+            None => panic!(
+                "({}, {}) is outside a {} by {} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.offset(x, y) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!(
+                "({}, {}) is outside a {} by {} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn grid_of(lines: &[&str]) -> Grid<char> {
+    Grid::parse(lines).unwrap()
+}
+
+#[test]
+fn test_parse_and_index() {
+    let grid = grid_of(&["ab", "cd", "ef"]);
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert_eq!(grid[(1, 0)], 'b');
+    assert_eq!(grid[(0, 2)], 'e');
+    assert_eq!(grid.get(1, 2), Some(&'f'));
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(grid.get(-1_i128, 0), None);
+    assert!(grid.contains(1_i64, 1));
+    assert!(!grid.contains(0, 3));
+    assert_eq!(grid.position(|c| *c == 'd'), Some((1, 1)));
+    assert_eq!(grid.to_string(), "ab\ncd\nef");
+}
+
+#[test]
+fn test_parse_ragged_rows() {
+    let lines = ["abc", "de"];
+    let error = Grid::parse(&lines).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2:3: expected a row of 3 cells, found 2"
+    );
+    let error = Grid::parse_with(&lines, |c| match c {
+        'a'..='c' => Ok(c),
+        _ => Err(AocError::parse(format!("unexpected `{}`", c))),
+    })
+    .unwrap_err();
+    assert_eq!(error.to_string(), "line 2:1: unexpected `d`");
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(
+        grid.neighbours4(0, 0).collect::<Vec<_>>(),
+        vec![(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours4(1, 1).count(), 4);
+    assert_eq!(
+        grid.neighbours8(2, 1).collect::<Vec<_>>(),
+        vec![(1, 0), (2, 0), (1, 1), (1, 2), (2, 2)]
+    );
+    assert_eq!(grid.neighbours8(1, 1).count(), 8);
+}
+
+#[test]
+fn test_rows_and_columns() {
+    let grid = grid_of(&["abc", "def"]);
+    assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    assert_eq!(grid.rows().count(), 2);
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+    assert_eq!(columns, vec!["ad", "be", "cf"]);
+}
+
+#[test]
+fn test_transforms() {
+    let grid = grid_of(&["abc", "def"]);
+    assert_eq!(grid.transpose(), grid_of(&["ad", "be", "cf"]));
+    assert_eq!(grid.rotate_clockwise(), grid_of(&["da", "eb", "fc"]));
+    assert_eq!(grid.rotate_counterclockwise(), grid_of(&["cf", "be", "ad"]));
+    assert_eq!(grid.flip_horizontal(), grid_of(&["cba", "fed"]));
+    assert_eq!(grid.flip_vertical(), grid_of(&["def", "abc"]));
+    assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    let mut grid = grid.map(|c| c.to_ascii_uppercase());
+    grid[(0, 1)] = 'x';
+    *grid.get_mut(2, 0).unwrap() = 'y';
+    assert_eq!(grid.to_string(), "ABy\nxEF");
+}
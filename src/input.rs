@@ -0,0 +1,129 @@
+//! Puzzle input read once into a single buffer.
+//!
+//! Days parse from `&str` lines borrowed out of an [`Input`] instead of each
+//! line being copied into its own `String`, and whatever a day keeps of the
+//! text can go on borrowing it. The text is normalised with
+//! [`normalize_input`] first, so solutions never see `\r` or stray blank lines.
+
+use std::fs;
+use std::io::{self, Read};
+
+use crate::advent_utils::{normalize_input, sections, InputWarning};
+use crate::data;
+use crate::error::AocError;
+use crate::grid::Grid;
+
+/// The whole text of one puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+    warnings: Vec<InputWarning>,
+}
+
+impl Input {
+    /// Normalise `text` and keep any warnings about it.
+    pub fn new(text: &str) -> Input {
+        let (text, warnings) = normalize_input(text);
+        Input { text, warnings }
+    }
+
+    pub fn from_file(path: &str) -> Result<Input, AocError> {
+        let text = fs::read_to_string(path).map_err(|source| AocError::Io {
+            path: path.to_string(),
+            source,
+        })?;
+        Ok(Input::new(&text))
+    }
+
+    /// Read a registered input such as `data/day_14_input.txt`, wherever
+    /// [`data::resolve`] finds it.
+    pub fn from_data_file(path: &str) -> Result<Input, AocError> {
+        let found = data::resolve(path)?;
+        Input::from_file(&found.to_string_lossy())
+    }
+
+    pub fn from_stdin() -> Result<Input, AocError> {
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|source| AocError::Io {
+                path: "<stdin>".to_string(),
+                source,
+            })?;
+        Ok(Input::new(&text))
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Anything suspicious left in the text after normalising it.
+    pub fn warnings(&self) -> &[InputWarning] {
+        &self.warnings
+    }
+
+    /// Every line, without its line ending.
+    pub fn lines(&self) -> Vec<&str> {
+        self.text.lines().collect()
+    }
+
+    /// The runs of non-blank lines between blank ones, each with the number of
+    /// lines before it, as split by [`sections`].
+    pub fn sections(&self) -> Vec<(usize, Vec<&str>)> {
+        sections(&self.lines())
+            .into_iter()
+            .map(|(start, section)| (start, section.to_vec()))
+            .collect()
+    }
+
+    /// The input as a grid of characters.
+    pub fn grid(&self) -> Result<Grid<char>, AocError> {
+        Grid::parse(&self.lines())
+    }
+}
+
+#[test]
+fn test_input_views() {
+    let input = Input::new("#.\n.#\n\nabc\r\n");
+    assert_eq!(input.lines(), vec!["#.", ".#", "", "abc"]);
+    assert_eq!(
+        input.sections(),
+        vec![(0, vec!["#.", ".#"]), (3, vec!["abc"])]
+    );
+    // lines point into the input rather than copies of it
+    let text = input.text().as_bytes().as_ptr_range();
+    assert!(input
+        .lines()
+        .iter()
+        .all(|line| text.contains(&line.as_ptr())));
+    let error = input.grid().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 3:1: expected a row of 2 cells, found 0"
+    );
+    let grid = Input::new("#.\n.#\n").grid().unwrap();
+    assert_eq!(grid.to_string(), "#.\n.#");
+}
+
+#[test]
+fn test_input_from_missing_file() {
+    let error = Input::from_file("data/no_such_file.txt").unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("could not read data/no_such_file.txt: "));
+    let error = Input::from_data_file("data/no_such_file.txt").unwrap_err();
+    assert!(matches!(error, AocError::NotFound { .. }));
+}
+
+#[test]
+fn test_input_is_normalized() {
+    let input = Input::new("\u{feff}#.#\r\n.#. \r\n\r\n##.\r\n\r\n");
+    assert_eq!(input.text(), "#.#\n.#.\n\n##.\n");
+    assert_eq!(input.sections().len(), 2);
+    assert!(input.warnings().is_empty());
+    let input = Input::new("rn=1,cm-\u{2013}\n");
+    assert_eq!(
+        input.warnings()[0].to_string(),
+        "line 1:9: unexpected non-ASCII character '\u{2013}' (U+2013)"
+    );
+}
@@ -0,0 +1,383 @@
+//! Half-open integer intervals, sets of them, and maps that shift each
+//! interval by its own offset.
+//!
+//! Puzzles that talk about billions of values usually only ever treat them
+//! in a handful of contiguous runs, so these types work on the runs instead
+//! of the values.
+
+use std::fmt;
+
+/// The integers from `start` up to but not including `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Interval {
+        Interval { start, end }
+    }
+
+    /// The `length` integers starting at `start`.
+    pub const fn with_length(start: i64, length: i64) -> Interval {
+        Interval::new(start, start + length)
+    }
+
+    /// How many integers the interval holds; 0 if `end` is not after `start`.
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The integers in both intervals, or `None` if they do not overlap.
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// The interval moved `offset` along.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of integers kept as sorted, disjoint, non-adjacent, non-empty intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> RangeSet {
+        RangeSet::default()
+    }
+
+    /// The intervals of the set, in order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// How many integers the set holds.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// The smallest integer in the set.
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// The largest integer in the set.
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    /// Add every integer of `interval` to the set, merging any intervals it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        // everything from `first` up to `last` overlaps or touches `interval`
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, existing| {
+                Interval::new(
+                    merged.start.min(existing.start),
+                    merged.end.max(existing.end),
+                )
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for interval in &other.intervals {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut intersection = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            intersection.extend(a.intersection(&b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        // pieces of disjoint, non-adjacent intervals are themselves disjoint and non-adjacent
+        RangeSet {
+            intervals: intersection,
+        }
+    }
+
+    /// The integers in this set but not in `other`.
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut difference = Vec::new();
+        let mut j = 0;
+        for interval in &self.intervals {
+            let mut start = interval.start;
+            while j < other.intervals.len() && other.intervals[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.intervals.len() && other.intervals[k].start < interval.end {
+                let removed = other.intervals[k];
+                if removed.start > start {
+                    difference.push(Interval::new(start, removed.start));
+                }
+                start = start.max(removed.end);
+                k += 1;
+            }
+            if start < interval.end {
+                difference.push(Interval::new(start, interval.end));
+            }
+        }
+        RangeSet {
+            intervals: difference,
+        }
+    }
+
+    /// Split the set into the integers below `value` and those at or above it.
+    pub fn split_at(&self, value: i64) -> (RangeSet, RangeSet) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for interval in &self.intervals {
+            if interval.end <= value {
+                below.push(*interval);
+            } else if interval.start >= value {
+                above.push(*interval);
+            } else {
+                below.push(Interval::new(interval.start, value));
+                above.push(Interval::new(value, interval.end));
+            }
+        }
+        (RangeSet { intervals: below }, RangeSet { intervals: above })
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> RangeSet {
+        let mut set = RangeSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> RangeSet {
+        let mut set = RangeSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl fmt::Display for RangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", interval)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// A map that moves every value in each of its pieces by that piece's
+/// offset, and leaves values outside every piece where they are.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    /// Sorted, disjoint source intervals and the offset applied to each.
+    pieces: Vec<(Interval, i64)>,
+    covered: RangeSet,
+}
+
+impl PiecewiseMap {
+    pub fn new() -> PiecewiseMap {
+        PiecewiseMap::default()
+    }
+
+    /// Move the values of `source` by `offset`. Values that an earlier piece
+    /// already covers keep that piece's offset.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        let uncovered = RangeSet::from(source).difference(&self.covered);
+        for interval in uncovered.intervals() {
+            let index = self
+                .pieces
+                .partition_point(|(piece, _)| piece.start < interval.start);
+            self.pieces.insert(index, (*interval, offset));
+            self.covered.insert(*interval);
+        }
+    }
+
+    /// The source intervals and their offsets, in order.
+    pub fn pieces(&self) -> &[(Interval, i64)] {
+        &self.pieces
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        let index = self.pieces.partition_point(|(piece, _)| piece.end <= value);
+        match self.pieces.get(index) {
+            Some((piece, offset)) if piece.contains(value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Where every value of `values` ends up.
+    pub fn map_set(&self, values: &RangeSet) -> RangeSet {
+        let mut image = values.difference(&self.covered);
+        for (piece, offset) in &self.pieces {
+            for moved in RangeSet::from(*piece).intersection(values).intervals() {
+                image.insert(moved.shift(*offset));
+            }
+        }
+        image
+    }
+}
+
+#[test]
+fn test_interval() {
+    let interval = Interval::new(3, 7);
+    assert_eq!(interval.len(), 4);
+    assert!(interval.contains(3) && interval.contains(6) && !interval.contains(7));
+    assert_eq!(Interval::with_length(3, 4), interval);
+    assert!(Interval::new(5, 5).is_empty());
+    assert_eq!(Interval::new(5, 2).len(), 0);
+    assert_eq!(
+        interval.intersection(&Interval::new(5, 10)),
+        Some(Interval::new(5, 7))
+    );
+    assert_eq!(interval.intersection(&Interval::new(7, 10)), None);
+    assert_eq!(interval.shift(-3), Interval::new(0, 4));
+}
+
+#[test]
+fn test_range_set_insert_normalizes() {
+    let set: RangeSet = [
+        Interval::new(10, 12),
+        Interval::new(0, 2),
+        Interval::new(4, 6),
+        Interval::new(2, 3),
+        Interval::new(5, 11),
+        Interval::new(20, 20),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        set.intervals(),
+        &[Interval::new(0, 3), Interval::new(4, 12)]
+    );
+    assert_eq!(set.len(), 11);
+    assert_eq!((set.min(), set.max()), (Some(0), Some(11)));
+    assert!(set.contains(2) && !set.contains(3) && set.contains(11) && !set.contains(12));
+    assert_eq!(set.to_string(), "{[0, 3), [4, 12)}");
+}
+
+#[test]
+fn test_range_set_operations() {
+    let a: RangeSet = [Interval::new(0, 10), Interval::new(20, 30)]
+        .into_iter()
+        .collect();
+    let b: RangeSet = [Interval::new(5, 25), Interval::new(28, 40)]
+        .into_iter()
+        .collect();
+    assert_eq!(a.union(&b).intervals(), &[Interval::new(0, 40)]);
+    assert_eq!(
+        a.intersection(&b).intervals(),
+        &[
+            Interval::new(5, 10),
+            Interval::new(20, 25),
+            Interval::new(28, 30)
+        ]
+    );
+    assert_eq!(
+        a.difference(&b).intervals(),
+        &[Interval::new(0, 5), Interval::new(25, 28)]
+    );
+    assert_eq!(
+        b.difference(&a).intervals(),
+        &[Interval::new(10, 20), Interval::new(30, 40)]
+    );
+    assert!(a.difference(&a).is_empty());
+    assert_eq!(a.difference(&RangeSet::new()), a);
+    let (below, above) = a.split_at(25);
+    assert_eq!(
+        below.intervals(),
+        &[Interval::new(0, 10), Interval::new(20, 25)]
+    );
+    assert_eq!(above.intervals(), &[Interval::new(25, 30)]);
+    assert_eq!(below.union(&above), a);
+    let (below, above) = a.split_at(10);
+    assert_eq!(below.intervals(), &[Interval::new(0, 10)]);
+    assert_eq!(above.intervals(), &[Interval::new(20, 30)]);
+}
+
+#[test]
+fn test_piecewise_map() {
+    // seed-to-soil from the day 5 example: `50 98 2` and `52 50 48`
+    let mut map = PiecewiseMap::new();
+    map.insert(Interval::with_length(98, 2), 50 - 98);
+    map.insert(Interval::with_length(50, 48), 52 - 50);
+    assert_eq!(map.map(79), 81);
+    assert_eq!(map.map(98), 50);
+    assert_eq!(map.map(14), 14);
+    assert_eq!(map.map(100), 100);
+    let seeds: RangeSet = [Interval::with_length(79, 14), Interval::with_length(96, 6)]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        map.map_set(&seeds).intervals(),
+        &[
+            Interval::new(50, 52),
+            Interval::new(81, 95),
+            Interval::new(98, 102)
+        ]
+    );
+    // overlapping pieces keep the first offset
+    map.insert(Interval::new(90, 110), 1000);
+    assert_eq!(map.map(95), 97);
+    assert_eq!(map.map(105), 1105);
+    assert_eq!(map.pieces().len(), 3);
+}
@@ -1 +1,48 @@
+//! Advent of Code 2023 solutions.
+//!
+//! Each solved day lives in its own module, `day01` to `day19`, with its
+//! puzzle types and part functions public so other tools can reuse them.
+//! Every day also implements [`solution::Solution`] and is listed in
+//! [`registry::DAYS`], which is what the `aoc` binary runs. Days whose input
+//! is a map of characters parse it into a [`grid::Grid`], and positions and
+//! headings on it are [`point::Point`]s and [`point::Direction`]s. Loops
+//! traced across it can be measured with [`geometry`].
+
 pub mod advent_utils;
+pub mod answers;
+pub mod cycle;
+pub mod data;
+pub mod differential;
+pub mod error;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod intervals;
+pub mod math;
+pub mod point;
+pub mod registry;
+pub mod render;
+pub mod report;
+pub mod solution;
+pub mod stepper;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day18;
+pub mod day19;
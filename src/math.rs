@@ -0,0 +1,168 @@
+//! Number theory for puzzles built from cycles: greatest common divisors,
+//! least common multiples and the Chinese Remainder Theorem.
+//!
+//! Anything that can outgrow an `i64` returns an `Option` rather than wrapping.
+
+/// The greatest common divisor of `a` and `b`, never negative. `gcd(0, 0)` is 0.
+///
+/// Panics if the answer is 2^63, which only happens when both are
+/// `i64::MIN` or one is `i64::MIN` and the other 0.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    i64::try_from(a).expect("the gcd is 2^63, which does not fit in an i64")
+}
+
+/// The least common multiple of `a` and `b`, never negative, or `None` if it
+/// does not fit in an `i64`. It is 0 if either is 0.
+pub fn lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)?.checked_abs()
+}
+
+/// The greatest common divisor of every value; 0 for no values.
+pub fn gcd_of(values: &[i64]) -> i64 {
+    values.iter().fold(0, |divisor, value| gcd(divisor, *value))
+}
+
+/// The least common multiple of every value; 1 for no values, and `None` if
+/// it does not fit in an `i64`.
+pub fn lcm_of(values: &[i64]) -> Option<i64> {
+    values
+        .iter()
+        .try_fold(1, |multiple, value| lcm(multiple, *value))
+}
+
+/// Extended Euclid: `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_wide(a.into(), b.into());
+    // |x| <= |b| / g and |y| <= |a| / g, so only g itself can be out of range
+    (
+        i64::try_from(g).expect("the gcd is 2^63, which does not fit in an i64"),
+        x as i64,
+        y as i64,
+    )
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x = 1 (mod modulus)`, or `None` if `a`
+/// and `modulus` share a factor or `modulus` is not positive.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd_wide(a.into(), modulus.into());
+    (g == 1).then(|| x.rem_euclid(modulus.into()) as i64)
+}
+
+/// Solve `x = residue (mod modulus)` for every `(residue, modulus)` pair at
+/// once. Moduli do not have to be coprime.
+///
+/// Returns `(x, m)` where every solution is `x` plus a multiple of `m`, the
+/// least common multiple of the moduli, and `0 <= x < m`. Returns `None` if
+/// the congruences contradict each other, a modulus is not positive, or `m`
+/// does not fit in an `i64`.
+pub fn chinese_remainder(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut m): (i128, i128) = (0, 1);
+    for &(residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let (residue, modulus) = (
+            i128::from(residue).rem_euclid(modulus.into()),
+            i128::from(modulus),
+        );
+        // x + m * k = residue (mod modulus), solvable when g divides the difference
+        let (g, p, _) = extended_gcd_wide(m, modulus);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = modulus / g;
+        let k = (difference / g % step * p).rem_euclid(step);
+        let combined = m.checked_mul(step)?;
+        i64::try_from(combined).ok()?;
+        x = (x + m * k).rem_euclid(combined);
+        m = combined;
+    }
+    Some((x as i64, m as i64))
+}
+
+#[test]
+fn test_gcd_and_lcm() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(-12, 18), 6);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(gcd(0, 0), 0);
+    assert_eq!(gcd(i64::MIN, 3), 1);
+    assert_eq!(lcm(4, 6), Some(12));
+    assert_eq!(lcm(-4, 6), Some(12));
+    assert_eq!(lcm(0, 6), Some(0));
+    assert_eq!(lcm(i64::MAX, 2), None);
+    assert_eq!(lcm(1 << 40, 1 << 50), Some(1 << 50));
+}
+
+#[test]
+fn test_gcd_and_lcm_of_slices() {
+    assert_eq!(lcm_of(&[2, 3, 4]), Some(12));
+    assert_eq!(lcm_of(&[2, 7, 3]), Some(42));
+    assert_eq!(lcm_of(&[]), Some(1));
+    assert_eq!(lcm_of(&[1 << 40, 3 << 30, 5 << 20]), Some(15 << 40));
+    assert_eq!(lcm_of(&[3, i64::MAX, i64::MAX - 1]), None);
+    assert_eq!(gcd_of(&[12, 18, 27]), 3);
+    assert_eq!(gcd_of(&[]), 0);
+}
+
+#[test]
+fn test_extended_gcd_and_inverse() {
+    for (a, b) in [(240, 46), (46, 240), (-240, 46), (17, 0), (0, 5), (7, 7)] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(g, gcd(a, b));
+        assert_eq!(a * x + b * y, g);
+    }
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(6, 9), None);
+    assert_eq!(mod_inverse(3, 0), None);
+    assert_eq!(mod_inverse(5, 1), Some(0));
+}
+
+#[test]
+fn test_chinese_remainder() {
+    assert_eq!(
+        chinese_remainder(&[(2, 3), (3, 5), (2, 7)]),
+        Some((23, 105))
+    );
+    // moduli that share factors
+    assert_eq!(chinese_remainder(&[(3, 4), (5, 6)]), Some((11, 12)));
+    assert_eq!(chinese_remainder(&[(1, 4), (2, 6)]), None);
+    assert_eq!(chinese_remainder(&[(-1, 5)]), Some((4, 5)));
+    assert_eq!(chinese_remainder(&[]), Some((0, 1)));
+    assert_eq!(chinese_remainder(&[(0, 0)]), None);
+    // paths that each come back after a multiple of their own length line up at the lcm
+    assert_eq!(
+        chinese_remainder(&[(0, 263 * 79), (0, 263 * 73), (0, 263 * 71)]),
+        Some((0, 263 * 79 * 73 * 71))
+    );
+    assert_eq!(chinese_remainder(&[(1, i64::MAX), (0, i64::MAX - 1)]), None);
+}
@@ -0,0 +1,247 @@
+//! Points on the puzzle plane and the four directions between them.
+//!
+//! Like [`crate::grid`], `x` grows to the right and `y` grows downwards, so
+//! [`Direction::Up`] is a step towards `y = 0`.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::error::AocError;
+
+/// A position, or the offset between two positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+
+    /// The point `distance` steps away in `direction`.
+    pub fn step_by(self, direction: Direction, distance: i64) -> Point {
+        self + direction.offset() * distance
+    }
+
+    /// The four points one step away, in the order of [`Direction::ALL`].
+    pub fn neighbours(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    /// A grid position as a point.
+    fn from((x, y): (usize, usize)) -> Point {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// One of the four directions along the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parse the `U`, `R`, `D` or `L` puzzles use for a direction.
+    pub fn from_char(c: char) -> Result<Direction, AocError> {
+        match c {
+            'U' => Ok(Direction::Up),
+            'R' => Ok(Direction::Right),
+            'D' => Ok(Direction::Down),
+            'L' => Ok(Direction::Left),
+            _ => Err(AocError::parse(format!(
+                "expected a direction of `U`, `R`, `D` or `L`, found `{}`",
+                c
+            ))),
+        }
+    }
+
+    /// The direction a single step of `offset` goes in, if it is one step along the grid.
+    pub fn from_offset(offset: Point) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == offset)
+    }
+
+    /// The step this direction takes.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// A quarter turn anticlockwise, as seen on the page.
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    /// A quarter turn clockwise, as seen on the page.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// An arrow pointing this way, for drawing.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    /// Whether the direction runs along a row rather than a column.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+#[test]
+fn test_point_arithmetic() {
+    let point = Point::new(2, 3);
+    assert_eq!(point + Point::new(1, -1), Point::new(3, 2));
+    assert_eq!(point - Point::new(2, 3), Point::ORIGIN);
+    assert_eq!(point * 3, Point::new(6, 9));
+    assert_eq!(-point, Point::new(-2, -3));
+    let mut moved = point;
+    moved += Point::new(1, 1);
+    moved -= Point::new(0, 2);
+    assert_eq!(moved, Point::new(3, 2));
+    assert_eq!(point.manhattan_distance(Point::new(-1, 5)), 5);
+    assert_eq!(Point::from((4_usize, 1_usize)), Point::new(4, 1));
+}
+
+#[test]
+fn test_point_steps() {
+    let point = Point::new(2, 3);
+    assert_eq!(point.step(Direction::Up), Point::new(2, 2));
+    assert_eq!(point.step(Direction::Left), Point::new(1, 3));
+    assert_eq!(point.step_by(Direction::Down, 4), Point::new(2, 7));
+    assert_eq!(
+        point.neighbours(),
+        [
+            Point::new(2, 2),
+            Point::new(3, 3),
+            Point::new(2, 4),
+            Point::new(1, 3)
+        ]
+    );
+}
+
+#[test]
+fn test_direction_turns() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        assert_eq!(
+            direction.offset() + direction.reverse().offset(),
+            Point::ORIGIN
+        );
+        assert_eq!(Direction::from_offset(direction.offset()), Some(direction));
+    }
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::from_offset(Point::new(1, 1)), None);
+    assert!(Direction::Left.is_horizontal());
+    assert!(!Direction::Down.is_horizontal());
+}
+
+#[test]
+fn test_direction_from_char() {
+    assert_eq!(Direction::from_char('R').unwrap(), Direction::Right);
+    assert_eq!(Direction::from_char('U').unwrap(), Direction::Up);
+    assert_eq!(
+        Direction::from_char('X').unwrap_err().to_string(),
+        "expected a direction of `U`, `R`, `D` or `L`, found `X`"
+    );
+}
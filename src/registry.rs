@@ -0,0 +1,205 @@
+//! Every solved day, its inputs and how to solve it.
+
+use rand::rngs::StdRng;
+
+use crate::error::AocError;
+use crate::render::Image;
+use crate::solution::{parse, Answer, Parsed, Part};
+use crate::{
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day18, day19,
+};
+
+type ParseFn = for<'a> fn(&[&'a str]) -> Result<Box<dyn Parsed + 'a>, AocError>;
+type GenerateFn = fn(&mut StdRng, usize) -> String;
+
+/// A registered day: its number, the puzzle inputs it ships with and how to solve it.
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    example: Option<&'static str>,
+    /// Some puzzles give part two its own example.
+    part_two_example: Option<&'static str>,
+    parse: ParseFn,
+    generate: GenerateFn,
+}
+
+impl Day {
+    pub fn example(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::Two => self.part_two_example.or(self.example),
+            Part::One => self.example,
+        }
+    }
+
+    /// Parse `lines` once; the result can solve both parts.
+    pub fn parse<'a>(&self, lines: &[&'a str]) -> Result<Box<dyn Parsed + 'a>, AocError> {
+        (self.parse)(lines)
+    }
+
+    pub fn solve(&self, lines: &[&str], part: Part) -> Result<Answer, AocError> {
+        Ok(self.parse(lines)?.solve(part))
+    }
+
+    /// A random input for the day, with `size` setting how large it is in a
+    /// way that depends on the puzzle.
+    pub fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        (self.generate)(rng, size)
+    }
+
+    /// Draw `lines` the way the day does, or `None` if it draws nothing.
+    pub fn render(&self, lines: &[&str]) -> Result<Option<Image>, AocError> {
+        Ok(self.parse(lines)?.render())
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        input: "data/day_one_input.txt",
+        example: None,
+        part_two_example: None,
+        parse: parse::<day01::DayOne>,
+        generate: day01::generate_input,
+    },
+    Day {
+        number: 2,
+        input: "data/day_two_input.txt",
+        example: None,
+        part_two_example: None,
+        parse: parse::<day02::DayTwo>,
+        generate: day02::generate_input,
+    },
+    Day {
+        number: 3,
+        input: "data/day_three_input.txt",
+        example: Some("data/day3_test.txt"),
+        part_two_example: None,
+        parse: parse::<day03::DayThree>,
+        generate: day03::generate_input,
+    },
+    Day {
+        number: 4,
+        input: "data/day_four_input.txt",
+        example: Some("data/day_four_test_input.txt"),
+        part_two_example: None,
+        parse: parse::<day04::DayFour>,
+        generate: day04::generate_input,
+    },
+    Day {
+        number: 5,
+        input: "data/day_five_input.txt",
+        example: Some("data/day_five_test_input.txt"),
+        part_two_example: None,
+        parse: parse::<day05::DayFive>,
+        generate: day05::generate_input,
+    },
+    Day {
+        number: 6,
+        input: "data/day_six_input.txt",
+        example: Some("data/day_six_test_input.txt"),
+        part_two_example: None,
+        parse: parse::<day06::DaySix>,
+        generate: day06::generate_input,
+    },
+    Day {
+        number: 7,
+        input: "data/day_seven_input.txt",
+        example: Some("data/day_seven_test_input.txt"),
+        part_two_example: None,
+        parse: parse::<day07::DaySeven>,
+        generate: day07::generate_input,
+    },
+    Day {
+        number: 8,
+        input: "data/day_eight_input.txt",
+        example: Some("data/day_eight_test_input.txt"),
+        part_two_example: Some("data/day_eight_part_two_test.txt"),
+        parse: parse::<day08::DayEight>,
+        generate: day08::generate_input,
+    },
+    Day {
+        number: 9,
+        input: "data/day_nine_input.txt",
+        example: Some("data/day_nine_test.txt"),
+        part_two_example: None,
+        parse: parse::<day09::DayNine>,
+        generate: day09::generate_input,
+    },
+    Day {
+        number: 10,
+        input: "data/day_ten_input.txt",
+        example: Some("data/day_ten_test.txt"),
+        part_two_example: None,
+        parse: parse::<day10::DayTen>,
+        generate: day10::generate_input,
+    },
+    Day {
+        number: 11,
+        input: "data/day_eleven.txt",
+        example: Some("data/day_eleven_test.txt"),
+        part_two_example: None,
+        parse: parse::<day11::DayEleven>,
+        generate: day11::generate_input,
+    },
+    Day {
+        number: 12,
+        input: "data/day_twelve_input.txt",
+        example: Some("data/day_twelve_test.txt"),
+        part_two_example: None,
+        parse: parse::<day12::DayTwelve>,
+        generate: day12::generate_input,
+    },
+    Day {
+        number: 13,
+        input: "data/day_thirteen_input.txt",
+        example: Some("data/day_thirteen_test.txt"),
+        part_two_example: None,
+        parse: parse::<day13::DayThirteen>,
+        generate: day13::generate_input,
+    },
+    Day {
+        number: 14,
+        input: "data/day_14_input.txt",
+        example: Some("data/day_14_test.txt"),
+        part_two_example: None,
+        parse: parse::<day14::DayFourteen>,
+        generate: day14::generate_input,
+    },
+    Day {
+        number: 15,
+        input: "data/day_15_input.txt",
+        example: Some("data/day_15_test.txt"),
+        part_two_example: None,
+        parse: parse::<day15::DayFifteen>,
+        generate: day15::generate_input,
+    },
+    Day {
+        number: 16,
+        input: "data/day_16_input.txt",
+        example: Some("data/day_16_test_input.txt"),
+        part_two_example: None,
+        parse: parse::<day16::DaySixteen>,
+        generate: day16::generate_input,
+    },
+    Day {
+        number: 18,
+        input: "data/day_18_input.txt",
+        example: Some("data/day_18_test.txt"),
+        part_two_example: None,
+        parse: parse::<day18::DayEighteen>,
+        generate: day18::generate_input,
+    },
+    Day {
+        number: 19,
+        input: "data/day_19_input.txt",
+        example: Some("data/day_19_test_input.txt"),
+        part_two_example: None,
+        parse: parse::<day19::DayNineteen>,
+        generate: day19::generate_input,
+    },
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
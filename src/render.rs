@@ -0,0 +1,207 @@
+//! Pictures of character grids, for seeing what a solution did.
+//!
+//! An [`Image`] starts as one pixel per cell, coloured by a [`Palette`] that
+//! maps each character to a colour. Overlays then pick out cells on top, such
+//! as a loop's path or the tiles a beam visited, before the image is scaled up
+//! and saved as a PPM or PNG file.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::point::Point;
+
+/// Red, green and blue.
+pub type Colour = [u8; 3];
+
+pub const BLACK: Colour = [0, 0, 0];
+pub const WHITE: Colour = [255, 255, 255];
+pub const GREY: Colour = [96, 96, 96];
+pub const RED: Colour = [220, 50, 47];
+pub const YELLOW: Colour = [250, 200, 40];
+pub const GREEN: Colour = [60, 180, 75];
+pub const BLUE: Colour = [40, 110, 220];
+
+/// The colour each character of a grid is drawn in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colours: HashMap<char, Colour>,
+    /// The colour of any character without one of its own.
+    default: Colour,
+}
+
+impl Palette {
+    pub fn new(default: Colour) -> Palette {
+        Palette {
+            colours: HashMap::new(),
+            default,
+        }
+    }
+
+    /// Draw every character in `characters` in `colour`.
+    pub fn with(mut self, characters: &str, colour: Colour) -> Palette {
+        for character in characters.chars() {
+            self.colours.insert(character, colour);
+        }
+        self
+    }
+
+    pub fn colour(&self, character: char) -> Colour {
+        self.colours
+            .get(&character)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+/// A picture made of square pixels, stored a row at a time from the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Colour) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// One pixel per cell of `grid`, in the palette's colour for it.
+    pub fn from_grid(grid: &Grid<char>, palette: &Palette) -> Image {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.rows().flatten().map(|c| palette.colour(*c)).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The colour at `point`, if it is inside the image.
+    pub fn get(&self, point: Point) -> Option<Colour> {
+        self.index(point).map(|index| self.pixels[index])
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// Colour the pixel at `point`. Points outside the image are ignored.
+    pub fn paint(&mut self, point: Point, colour: Colour) {
+        if let Some(index) = self.index(point) {
+            self.pixels[index] = colour;
+        }
+    }
+
+    /// Highlight `points` by mixing `colour` half and half with what is
+    /// already there, so the cells underneath still show through.
+    pub fn overlay(&mut self, points: impl IntoIterator<Item = Point>, colour: Colour) {
+        for point in points {
+            if let Some(index) = self.index(point) {
+                let pixel = &mut self.pixels[index];
+                for (channel, highlight) in pixel.iter_mut().zip(colour) {
+                    *channel = ((u16::from(*channel) + u16::from(highlight)) / 2) as u8;
+                }
+            }
+        }
+    }
+
+    /// The image with every pixel drawn as a `factor` by `factor` square.
+    pub fn scaled(&self, factor: usize) -> Image {
+        let width = self.width * factor;
+        let pixels = (0..self.height * factor)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+        Image {
+            width,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    /// Write the image as a binary PPM, which anything can read but nothing compresses.
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.bytes())
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> io::Result<()> {
+        let too_large = |_| io::Error::new(io::ErrorKind::InvalidInput, "image is too large");
+        let width = u32::try_from(self.width).map_err(too_large)?;
+        let height = u32::try_from(self.height).map_err(too_large)?;
+        let mut encoder = png::Encoder::new(writer, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes())?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Save the image to `path`, as a PPM if it ends in `.ppm` and a PNG otherwise.
+    pub fn save(&self, path: &str) -> Result<(), AocError> {
+        let io_error = |source| AocError::Io {
+            path: path.to_string(),
+            source,
+        };
+        let mut writer = BufWriter::new(File::create(path).map_err(io_error)?);
+        if path.ends_with(".ppm") {
+            self.write_ppm(&mut writer).map_err(io_error)?;
+        } else {
+            self.write_png(&mut writer).map_err(io_error)?;
+        }
+        writer.flush().map_err(io_error)
+    }
+}
+
+#[test]
+fn test_image_from_grid() {
+    let grid = Grid::parse(&["#.", ".S"]).unwrap();
+    let palette = Palette::new(BLACK).with("#", WHITE).with("S", RED);
+    let mut image = Image::from_grid(&grid, &palette);
+    assert_eq!((image.width(), image.height()), (2, 2));
+    assert_eq!(image.get(Point::new(0, 0)), Some(WHITE));
+    assert_eq!(image.get(Point::new(1, 0)), Some(BLACK));
+    assert_eq!(image.get(Point::new(1, 1)), Some(RED));
+    assert_eq!(image.get(Point::new(2, 0)), None);
+    image.overlay([Point::new(0, 0), Point::new(1, 0), Point::new(-1, 0)], RED);
+    assert_eq!(image.get(Point::new(0, 0)), Some([237, 152, 151]));
+    assert_eq!(image.get(Point::new(1, 0)), Some([110, 25, 23]));
+    image.paint(Point::new(0, 1), BLUE);
+    assert_eq!(image.get(Point::new(0, 1)), Some(BLUE));
+    let scaled = image.scaled(3);
+    assert_eq!((scaled.width(), scaled.height()), (6, 6));
+    assert_eq!(scaled.get(Point::new(2, 5)), Some(BLUE));
+    assert_eq!(scaled.get(Point::new(3, 5)), Some(RED));
+}
+
+#[test]
+fn test_write_ppm() {
+    let image = Image::new(2, 1, GREY);
+    let mut bytes = Vec::new();
+    image.write_ppm(&mut bytes).unwrap();
+    assert_eq!(bytes, b"P6\n2 1\n255\n\x60\x60\x60\x60\x60\x60");
+    let mut bytes = Vec::new();
+    image.write_png(&mut bytes).unwrap();
+    assert!(bytes.starts_with(b"\x89PNG\r\n\x1a\n"));
+}
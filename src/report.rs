@@ -0,0 +1,113 @@
+//! Machine-readable records of what running a day produced.
+//!
+//! `aoc run --format json` prints one [`PartReport`] per part as a line of
+//! JSON, so dashboards can read answers and timings without scraping the text
+//! output.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::solution::{Answer, Part};
+
+/// The answer to one part of one day, how long it took and which input it was for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartReport {
+    pub day: u8,
+    pub part: u8,
+    /// Where the input was read from, as given on the command line or registered.
+    pub input: String,
+    /// The SHA-256 of the input after normalising, in hex, which stays the
+    /// same wherever the file lives and whatever line endings it has.
+    pub input_sha256: String,
+    /// `null` for parts that are not solved yet.
+    pub answer: Option<i128>,
+    /// How long parsing the input for this part took.
+    pub parse_nanos: u64,
+    pub solve_nanos: u64,
+}
+
+impl PartReport {
+    pub fn new(
+        day: u8,
+        part: Part,
+        input: &str,
+        input_sha256: &str,
+        answer: &Answer,
+        parse_time: Duration,
+        solve_time: Duration,
+    ) -> PartReport {
+        PartReport {
+            day,
+            part: match part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            input: input.to_string(),
+            input_sha256: input_sha256.to_string(),
+            answer: match answer {
+                Answer::Number(number) => Some(*number),
+                Answer::Unsolved => None,
+            },
+            parse_nanos: nanos(parse_time),
+            solve_nanos: nanos(solve_time),
+        }
+    }
+
+    /// The report as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a report always serialises")
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// The SHA-256 of `text` in lowercase hex.
+pub fn sha256_hex(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[test]
+fn test_sha256_hex() {
+    assert_eq!(
+        sha256_hex(""),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        sha256_hex("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_part_report_json() {
+    let report = PartReport::new(
+        7,
+        Part::Two,
+        "data/day_seven_test.txt",
+        "ab12",
+        &Answer::Number(5905),
+        Duration::from_micros(15),
+        Duration::from_nanos(900),
+    );
+    assert_eq!(
+        report.to_json(),
+        r#"{"day":7,"part":2,"input":"data/day_seven_test.txt","input_sha256":"ab12","answer":5905,"parse_nanos":15000,"solve_nanos":900}"#
+    );
+    let report = PartReport::new(
+        1,
+        Part::One,
+        "<stdin>",
+        "ab12",
+        &Answer::Unsolved,
+        Duration::ZERO,
+        Duration::ZERO,
+    );
+    assert!(report.to_json().contains(r#""answer":null"#));
+}
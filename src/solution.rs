@@ -0,0 +1,127 @@
+//! The [`Solution`] trait every day implements and the [`Answer`] it produces.
+
+use std::fmt;
+
+use crate::error::AocError;
+use crate::render::Image;
+use crate::stepper::Playback;
+
+/// Which half of a day's puzzle to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// The answer a solution produces for one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                /// A number too large for an `i128` is left unsolved rather
+                /// than wrapped round to a wrong answer.
+                fn from(number: $integer) -> Self {
+                    i128::try_from(number).map_or(Answer::Unsolved, Answer::Number)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(i32, i64, i128, usize, u128);
+
+/// A single day's puzzle: parse the input once, then solve either part from it.
+///
+/// The parsed input may borrow from the lines it was parsed from, which in
+/// turn borrow from the [`Input`](crate::input::Input) they were read into.
+pub trait Solution {
+    type Input<'a>;
+
+    fn parse<'a>(lines: &[&'a str]) -> Result<Self::Input<'a>, AocError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Answer;
+
+    fn part_two(input: &Self::Input<'_>) -> Answer;
+
+    /// A picture of the input and what the solution made of it, for days
+    /// that can draw one.
+    fn render(_input: &Self::Input<'_>) -> Option<Image> {
+        None
+    }
+
+    /// A replay of the step function at the heart of the solution, for days
+    /// built around one.
+    fn stepper<'b>(_input: &'b Self::Input<'_>) -> Option<Box<dyn Playback + 'b>> {
+        None
+    }
+}
+
+/// A day's input after parsing, ready to solve either part without parsing again.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Answer;
+
+    fn render(&self) -> Option<Image>;
+
+    fn stepper(&self) -> Option<Box<dyn Playback + '_>>;
+}
+
+struct ParsedInput<'a, S: Solution> {
+    input: S::Input<'a>,
+}
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => S::part_one(&self.input),
+            Part::Two => S::part_two(&self.input),
+        }
+    }
+
+    fn render(&self) -> Option<Image> {
+        S::render(&self.input)
+    }
+
+    fn stepper(&self) -> Option<Box<dyn Playback + '_>> {
+        S::stepper(&self.input)
+    }
+}
+
+/// Parse `lines` with `S`, hiding the input type so every day can be handled alike.
+pub fn parse<'a, S: Solution + 'static>(
+    lines: &[&'a str],
+) -> Result<Box<dyn Parsed + 'a>, AocError> {
+    let input = S::parse(lines)?;
+    Ok(Box::new(ParsedInput::<S> { input }))
+}
+
+#[test]
+fn test_answer_from_integer() {
+    assert_eq!(Answer::from(-3_i64), Answer::Number(-3));
+    assert_eq!(Answer::from(7_usize), Answer::Number(7));
+    assert_eq!(Answer::from(i128::MAX as u128), Answer::Number(i128::MAX));
+    assert_eq!(Answer::from(u128::MAX), Answer::Unsolved);
+}
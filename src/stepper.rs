@@ -0,0 +1,171 @@
+//! Replaying a simulation one step at a time.
+//!
+//! A [`Simulation`] knows its first state, how to step from one state to the
+//! next and how to draw a state as text. A [`Stepper`] runs it forwards on
+//! demand and keeps every state it has reached, so stepping back only goes
+//! through that history and never simulates anything again. The `aoc step`
+//! command draws a stepper's frames in the terminal.
+
+/// A puzzle whose answer comes from repeatedly stepping a state.
+pub trait Simulation {
+    type State;
+
+    fn initial(&self) -> Self::State;
+
+    /// The state after `state`, or `None` once the simulation has finished.
+    fn step(&self, state: &Self::State) -> Option<Self::State>;
+
+    /// `state` as text to show in the terminal, one line per row.
+    fn draw(&self, state: &Self::State) -> String;
+}
+
+/// Moving through a simulation's states, whatever their type, for the
+/// terminal to draw.
+pub trait Playback {
+    /// Move to the next state, simulating it if it has not been reached yet.
+    /// Returns whether there was one.
+    fn forward(&mut self) -> bool;
+
+    /// Move back to the previous state. Returns whether there was one.
+    fn back(&mut self) -> bool;
+
+    /// How many steps the current state is from the initial one.
+    fn position(&self) -> usize;
+
+    /// Whether the simulation has been seen to finish.
+    fn finished(&self) -> bool;
+
+    /// The current state, drawn.
+    fn frame(&self) -> String;
+}
+
+/// A simulation and the states it has reached so far.
+pub struct Stepper<S: Simulation> {
+    simulation: S,
+    history: Vec<S::State>,
+    position: usize,
+    finished: bool,
+}
+
+impl<S: Simulation> Stepper<S> {
+    pub fn new(simulation: S) -> Stepper<S> {
+        let initial = simulation.initial();
+        Stepper {
+            simulation,
+            history: vec![initial],
+            position: 0,
+            finished: false,
+        }
+    }
+
+    pub fn state(&self) -> &S::State {
+        &self.history[self.position]
+    }
+
+    /// Every state reached so far, from the initial one.
+    pub fn history(&self) -> &[S::State] {
+        &self.history
+    }
+
+    /// Step forwards until the simulation finishes or `limit` steps have been
+    /// reached, and return the state it stops at.
+    pub fn run(&mut self, limit: usize) -> &S::State {
+        while self.position < limit && self.forward() {}
+        self.state()
+    }
+}
+
+impl<S: Simulation> Playback for Stepper<S> {
+    fn forward(&mut self) -> bool {
+        if self.position + 1 < self.history.len() {
+            self.position += 1;
+            return true;
+        }
+        if self.finished {
+            return false;
+        }
+        match self.simulation.step(self.state()) {
+            Some(next) => {
+                self.history.push(next);
+                self.position += 1;
+                true
+            }
+            None => {
+                self.finished = true;
+                false
+            }
+        }
+    }
+
+    fn back(&mut self) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+        self.position -= 1;
+        true
+    }
+
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    fn finished(&self) -> bool {
+        self.finished
+    }
+
+    fn frame(&self) -> String {
+        self.simulation.draw(self.state())
+    }
+}
+
+#[cfg(test)]
+struct Countdown(u32);
+
+#[cfg(test)]
+impl Simulation for Countdown {
+    type State = u32;
+
+    fn initial(&self) -> u32 {
+        self.0
+    }
+
+    fn step(&self, state: &u32) -> Option<u32> {
+        state.checked_sub(1)
+    }
+
+    fn draw(&self, state: &u32) -> String {
+        state.to_string()
+    }
+}
+
+#[test]
+fn test_stepper_history() {
+    let mut stepper = Stepper::new(Countdown(3));
+    assert_eq!(stepper.frame(), "3");
+    assert!(!stepper.back());
+    assert!(stepper.forward());
+    assert!(stepper.forward());
+    assert_eq!((stepper.position(), stepper.frame().as_str()), (2, "1"));
+    assert!(stepper.back());
+    assert_eq!(*stepper.state(), 2);
+    // stepping forwards again replays the history instead of simulating
+    assert!(stepper.forward());
+    assert_eq!(stepper.history(), &[3, 2, 1]);
+    assert!(stepper.forward());
+    assert!(!stepper.forward());
+    assert!(stepper.finished());
+    assert_eq!(*stepper.state(), 0);
+    assert!(stepper.back());
+    assert!(stepper.forward());
+    assert!(!stepper.forward());
+}
+
+#[test]
+fn test_stepper_run() {
+    let mut stepper = Stepper::new(Countdown(10));
+    assert_eq!(*stepper.run(4), 6);
+    assert!(!stepper.finished());
+    assert_eq!(*stepper.run(usize::MAX), 0);
+    assert!(stepper.finished());
+    assert_eq!(stepper.position(), 10);
+}